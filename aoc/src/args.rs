@@ -0,0 +1,109 @@
+use common::Options;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Command line arguments of the form `aoc <command> [--name value]...`.
+///
+/// A flag followed by another flag (or by nothing) is treated as a switch
+/// with no value.
+pub struct Args {
+    pub command: Option<String>,
+    flags: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    pub fn parse<I: Iterator<Item = String>>(args: I) -> Result<Args, String> {
+        let mut args = args.peekable();
+        let mut command = None;
+        let mut flags = HashMap::new();
+
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--") {
+                if let Some((name, value)) = name.split_once('=') {
+                    flags.insert(name.to_string(), Some(value.to_string()));
+                } else {
+                    let value = match args.peek() {
+                        Some(next) if !next.starts_with("--") => args.next(),
+                        _ => None,
+                    };
+                    flags.insert(name.to_string(), value);
+                }
+            } else if command.is_none() {
+                command = Some(arg);
+            } else {
+                return Err(format!("unexpected argument '{}'", arg));
+            }
+        }
+
+        return Ok(Args {
+            command: command,
+            flags: flags,
+        });
+    }
+
+    /// Returns the parsed value of `--name`, or `None` if it wasn't given.
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.flags.get(name) {
+            None => return Ok(None),
+            Some(None) => return Err(format!("--{} requires a value", name)),
+            Some(Some(value)) => {
+                return value
+                    .parse::<T>()
+                    .map(Some)
+                    .map_err(|_| format!("invalid value '{}' for --{}", value, name));
+            }
+        }
+    }
+
+    /// Whether the switch `--name` was given.
+    pub fn flag(&self, name: &str) -> bool {
+        return self.flags.contains_key(name);
+    }
+
+    /// Collects every flag not in `known` as a day-specific option. Switches
+    /// are given the value `true`.
+    pub fn options(&self, known: &[&str]) -> Options {
+        let mut options = Options::new();
+        for (name, value) in &self.flags {
+            if !known.contains(&name.as_str()) {
+                options.set(name, value.as_deref().unwrap_or("true"));
+            }
+        }
+        return options;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Args {
+        return Args::parse(line.split_whitespace().map(String::from)).unwrap();
+    }
+
+    #[test]
+    fn values() {
+        let args = parse("run --day 8 --part=2 --input -");
+        assert_eq!(args.command.as_deref(), Some("run"));
+        assert_eq!(args.value::<u32>("day"), Ok(Some(8)));
+        assert_eq!(args.value::<u32>("part"), Ok(Some(2)));
+        assert_eq!(args.value::<String>("input"), Ok(Some(String::from("-"))));
+        assert_eq!(args.value::<u32>("missing"), Ok(None));
+    }
+
+    #[test]
+    fn bad_values() {
+        let args = parse("run --day eight --part");
+        assert!(args.value::<u32>("day").is_err());
+        assert!(args.value::<u32>("part").is_err());
+    }
+
+    #[test]
+    fn options() {
+        let args = parse("run --day 9 --window 5 --verbose");
+        let options = args.options(&["day"]);
+        assert_eq!(options.get::<usize>("window"), Ok(Some(5)));
+        assert_eq!(options.get::<bool>("verbose"), Ok(Some(true)));
+        assert_eq!(options.get::<u32>("day"), Ok(None));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::args::Args;
+use crate::days::{self, Day};
+use crate::describe;
+use common::{DynSolution, Options};
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+const DEFAULT_REPS: usize = 5;
+
+#[derive(Debug, PartialEq)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        return Stats {
+            min: samples[0],
+            median: median,
+            max: samples[samples.len() - 1],
+        };
+    }
+}
+
+/// Runs `f` `reps` times, returning the timings and the last result.
+fn time<T, F: FnMut() -> T>(reps: usize, mut f: F) -> (Stats, T) {
+    let mut samples = Vec::with_capacity(reps);
+    let mut result = None;
+    for _ in 0..reps {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+
+    return (Stats::from_samples(samples), result.unwrap());
+}
+
+fn bench_day(day: &Day, reps: usize) -> Result<Vec<(&'static str, Stats)>, String> {
+    let input = day.default_input();
+    let text = input.read(day.examples).map_err(|e| describe(e, &input))?;
+
+    let (parse, solution) = time(reps, || (day.parse)(&text, &Options::new()));
+    let solution: Box<dyn DynSolution> = solution.map_err(|e| describe(e, &input))?;
+    let (part1, _) = time(reps, || solution.part1());
+    let (part2, _) = time(reps, || solution.part2());
+
+    return Ok(vec![("parse", parse), ("part1", part1), ("part2", part2)]);
+}
+
+/// `aoc bench [--day <n>] [--reps <n>] [--json]`
+pub fn bench(args: &Args) -> Result<(), String> {
+    let reps = args.value::<usize>("reps")?.unwrap_or(DEFAULT_REPS);
+    if reps == 0 {
+        return Err(String::from("--reps must be at least 1"));
+    }
+    let selected: Vec<&Day> = match args.value::<u32>("day")? {
+        Some(number) => vec![days::find(number).ok_or(format!("no solver for day {}", number))?],
+        None => days::DAYS.iter().collect(),
+    };
+    let json = args.flag("json");
+
+    let mut results = vec![];
+    for day in selected {
+        for (phase, stats) in bench_day(day, reps)? {
+            if json {
+                results.push(json!({
+                    "day": day.number,
+                    "phase": phase,
+                    "reps": reps,
+                    "min_ns": stats.min.as_nanos() as u64,
+                    "median_ns": stats.median.as_nanos() as u64,
+                    "max_ns": stats.max.as_nanos() as u64,
+                }));
+            } else {
+                println!(
+                    "Day {:>2} {:<5}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+                    day.number, phase, stats.min, stats.median, stats.max
+                );
+            }
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(stats, Stats { min: ms(1), median: ms(3), max: ms(5) });
+
+        let stats = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(stats, Stats { min: ms(1), median: ms(3), max: ms(8) });
+    }
+}
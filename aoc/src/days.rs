@@ -0,0 +1,49 @@
+use common::{parse_boxed, DynSolution, Error, Example, Input, Options, Solution};
+use std::path::PathBuf;
+
+type ParseFn = fn(&str, &Options) -> Result<Box<dyn DynSolution>, Error>;
+
+pub struct Day {
+    pub number: u32,
+    pub parse: ParseFn,
+    pub examples: &'static [Example],
+}
+
+impl Day {
+    /// The puzzle input checked in alongside the day's solver.
+    pub fn default_input(&self) -> Input {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day{}", self.number))
+            .join("input");
+        return Input::Path(path);
+    }
+}
+
+macro_rules! day {
+    ($number:expr, $solution:ty) => {
+        Day {
+            number: $number,
+            parse: parse_boxed::<$solution>,
+            examples: <$solution as Solution>::EXAMPLES,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day1::ExpenseReport),
+    day!(2, day2::PasswordDatabase),
+    day!(3, day3::Map),
+    day!(4, day4::PassportBatch),
+    day!(5, day5::BoardingPasses),
+    day!(6, day6::CustomsForms),
+    day!(7, day7::BagRules),
+    day!(8, day8::Program),
+    day!(9, day9::XmasData),
+    day!(10, day10::Adapters),
+    day!(11, day11::WaitingArea),
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    return DAYS.iter().find(|d| d.number == number);
+}
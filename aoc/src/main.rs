@@ -0,0 +1,95 @@
+mod args;
+mod bench;
+mod days;
+mod tools;
+mod verify;
+
+use args::Args;
+use common::{Error, Input};
+use days::Day;
+use std::process;
+
+const USAGE: &str = "\
+usage: aoc run --day <n> [--part <1|2>] [--input <path|->] [--example <name>] [--<option> <value>]...
+       aoc verify [--day <n>] [--answers <path>]
+       aoc bench [--day <n>] [--reps <n>] [--json]
+       aoc day1 [--k <n>] [--target <n>] [--all | --count] [--input <path|->] [--example <name>]
+       aoc day2 [--forbid <chars>] [--min-distinct <n>] [--regex <re>] [--positions chars|graphemes]
+                [--report [--format table|csv|json]] [--input <path|->]
+       aoc day3 [--slopes \"<right>,<down> <n>/<d> ...\"] [--costs] [--legend \"<char>=<name>:<cost> ...\"]
+                [--input <path|->] [--example <name>]
+       aoc day3 --optimal [--most] [--max-right <n>] [--max-down <n>] [--input <path|->]
+       aoc day3 --render <right>,<down> [--image <path.svg|path.ppm>] [--scale <n>] [--input <path|->]
+       aoc day4 [--part <1|2>] [--schema <path>] [--required <fields>] [--optional <fields>]
+                [--strict | --count] [--input <path|->] [--example <name>]
+       aoc day4 (--normalise [--layout line|lines] | --export csv|jsonl) [--strip-invalid] [--input <path|->]
+       aoc day5 [--encode <seat id>] [--input <path|->] [--example <name>]
+       aoc day6 [--input <path|->] [--example <name>]";
+
+/// Renders an error for the user, pointing parse errors at `input`.
+pub fn describe(err: Error, input: &Input) -> String {
+    match err {
+        Error::Parse(err) => return err.with_file(&input.name()).render(),
+        err => return err.to_string(),
+    }
+}
+
+/// Works out which input the user asked for: an example, a path or stdin,
+/// falling back to the day's checked-in input.
+pub fn select_input(args: &Args, day: &Day) -> Result<Input, String> {
+    match (args.value::<Input>("input")?, args.value::<String>("example")?) {
+        (Some(_), Some(_)) => return Err(String::from("--input and --example can't be used together")),
+        (Some(input), None) => return Ok(input),
+        (None, Some(name)) => return Ok(Input::Example(name)),
+        (None, None) => return Ok(day.default_input()),
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let number: u32 = args.value("day")?.ok_or("--day is required")?;
+    let day = days::find(number).ok_or(format!("no solver for day {}", number))?;
+    let parts = match args.value::<u32>("part")? {
+        Some(part) if part == 1 || part == 2 => vec![part],
+        Some(part) => return Err(format!("no such part {}", part)),
+        None => vec![1, 2],
+    };
+    let input = select_input(args, day)?;
+    let options = input
+        .options(day.examples)
+        .map_err(|e| describe(e, &input))?
+        .merged(&args.options(&["day", "part", "input", "example"]));
+
+    let text = input.read(day.examples).map_err(|e| describe(e, &input))?;
+    let solution = (day.parse)(&text, &options).map_err(|e| describe(e, &input))?;
+    for part in parts {
+        let answer = match part {
+            1 => solution.part1(),
+            _ => solution.part2(),
+        };
+        println!("Day {} part {}: {}", number, part, answer);
+    }
+
+    return Ok(());
+}
+
+fn main() {
+    let result = Args::parse(std::env::args().skip(1)).and_then(|args| {
+        match args.command.as_deref() {
+            Some("run") => return run(&args),
+            Some("verify") => return verify::verify(&args),
+            Some("bench") => return bench::bench(&args),
+            Some("day1") => return tools::day1::run(&args),
+            Some("day2") => return tools::day2::run(&args),
+            Some("day3") => return tools::day3::run(&args),
+            Some("day4") => return tools::day4::run(&args),
+            Some("day5") => return tools::day5::run(&args),
+            Some("day6") => return tools::day6::run(&args),
+            _ => return Err(String::from(USAGE)),
+        }
+    });
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
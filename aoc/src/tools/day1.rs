@@ -0,0 +1,54 @@
+use crate::args::Args;
+use crate::days;
+use crate::{describe, select_input};
+use common::Solution;
+use day1::{count_k_sums, find_all_k_sums, find_k_sum, ExpenseReport};
+
+fn show(entries: &[i64], indices: &[usize], target: i64) {
+    let values: Vec<String> = indices.iter().map(|&i| entries[i].to_string()).collect();
+    let lines: Vec<String> = indices.iter().map(|&i| (i + 1).to_string()).collect();
+    let product = indices
+        .iter()
+        .try_fold(1i64, |acc, &i| acc.checked_mul(entries[i]));
+
+    println!("{} = {}", values.join(" + "), target);
+    println!("Lines: {}", lines.join(", "));
+    match product {
+        Some(product) => println!("Product: {}", product),
+        None => println!("Product: overflows a 64-bit integer"),
+    }
+}
+
+/// `aoc day1 [--k <n>] [--target <n>] [--all | --count]`: finds `k` entries
+/// summing to the target and shows which they are, as well as their product.
+/// `--all` lists every such combination, and `--count` only counts them.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = days::find(1).unwrap();
+    let input = select_input(args, day)?;
+    let k = args.value::<usize>("k")?.unwrap_or(2);
+    let known = ["input", "example", "k", "all", "count"];
+    let report = ExpenseReport::from_input(&input, &args.options(&known)).map_err(|e| describe(e, &input))?;
+
+    let entries = report.entries();
+    let target = report.target();
+    if args.flag("count") {
+        match count_k_sums(entries, k, target) {
+            Some(count) => println!("{}", count),
+            None => return Err(String::from("too many combinations to count")),
+        }
+    } else if args.flag("all") {
+        let all = find_all_k_sums(entries, k, target);
+        for (n, indices) in all.iter().enumerate() {
+            if n > 0 {
+                println!();
+            }
+            show(entries, indices, target);
+        }
+        println!("{} combination(s)", all.len());
+    } else {
+        let indices = find_k_sum(entries, k, target).ok_or(format!("no {} entries sum to {}", k, target))?;
+        show(entries, &indices, target);
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,114 @@
+use crate::args::Args;
+use crate::days;
+use crate::tools::{csv_field, Format};
+use crate::{describe, select_input};
+use common::Solution;
+use day2::{extra_policies, Audit, LineReport, PasswordDatabase};
+use serde_json::json;
+
+fn print_lines(reports: &[LineReport]) {
+    for report in reports {
+        let results: Vec<String> = report
+            .results
+            .iter()
+            .map(|(name, result)| match result {
+                Ok(()) => format!("{} pass", name),
+                Err(reason) => format!("{} FAIL ({})", name, reason),
+            })
+            .collect();
+        println!("{:>4} {}: {}", report.entry.line, report.entry.password, results.join(", "));
+        for (name, warning) in &report.warnings {
+            println!("     warning: {}: {}", name, warning);
+        }
+    }
+}
+
+fn print_table(audit: &Audit) {
+    println!("{:<10} {:>8} {:>8}", "Policy", "Pass", "Fail");
+    for (name, pass, fail) in &audit.policies {
+        println!("{:<10} {:>8} {:>8}", name, pass, fail);
+    }
+
+    println!();
+    println!("{:<10} {:>8}", "Required", "Lines");
+    for (c, count) in &audit.required_chars {
+        println!("{:<10} {:>8}", c, count);
+    }
+
+    println!();
+    println!("{:>6} {:<10} Reason", "Line", "Policy");
+    for failure in &audit.failures {
+        println!("{:>6} {:<10} {}", failure.line, failure.policy, failure.reason);
+    }
+}
+
+/// One CSV table holding all three sections of the report. `section` says
+/// which a row belongs to, and the columns that don't apply to it are empty.
+fn print_csv(audit: &Audit) {
+    println!("section,policy,char,line,pass,fail,lines,reason");
+    for (name, pass, fail) in &audit.policies {
+        println!("policy,{},,,{},{},,", csv_field(name), pass, fail);
+    }
+    for (c, count) in &audit.required_chars {
+        println!("required_char,,{},,,,{},", csv_field(c), count);
+    }
+    for failure in &audit.failures {
+        println!(
+            "failure,{},,{},,,,{}",
+            csv_field(failure.policy),
+            failure.line,
+            csv_field(&failure.reason)
+        );
+    }
+}
+
+fn print_json(audit: &Audit) {
+    let policies: Vec<_> = audit
+        .policies
+        .iter()
+        .map(|(name, pass, fail)| json!({ "policy": name, "pass": pass, "fail": fail }))
+        .collect();
+    let required_chars: Vec<_> = audit
+        .required_chars
+        .iter()
+        .map(|(c, count)| json!({ "char": c, "lines": count }))
+        .collect();
+    let failures: Vec<_> = audit
+        .failures
+        .iter()
+        .map(|f| json!({ "line": f.line, "policy": f.policy, "reason": f.reason }))
+        .collect();
+
+    let report = json!({
+        "policies": policies,
+        "required_chars": required_chars,
+        "failures": failures,
+    });
+    println!("{}", serde_json::to_string_pretty(&report).unwrap());
+}
+
+/// `aoc day2 [--forbid <chars>] [--min-distinct <n>] [--regex <re>]
+/// [--positions chars|graphemes] [--report [--format table|csv|json]]`:
+/// shows which policies each password passes or fails, and why, or with
+/// `--report`, a summary of the whole database.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = days::find(2).unwrap();
+    let input = select_input(args, day)?;
+    let options = args.options(&["input", "example", "report", "format"]);
+    let db = PasswordDatabase::from_input(&input, &options).map_err(|e| describe(e, &input))?;
+    let extra = extra_policies(&options).map_err(|e| e.to_string())?;
+    let reports = db.check(&extra);
+
+    if !args.flag("report") {
+        print_lines(&reports);
+        return Ok(());
+    }
+
+    let audit = Audit::from_reports(&reports);
+    match args.value::<Format>("format")?.unwrap_or(Format::Table) {
+        Format::Table => print_table(&audit),
+        Format::Csv => print_csv(&audit),
+        Format::Json => print_json(&audit),
+    }
+    return Ok(());
+}
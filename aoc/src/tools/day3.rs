@@ -0,0 +1,130 @@
+use crate::args::Args;
+use crate::days;
+use crate::{describe, select_input};
+use common::{Error, Input, Solution};
+use day3::{Legend, Map, Objective, Slope, PUZZLE_SLOPES};
+use std::fs;
+use std::path::Path;
+
+/// Parses a whitespace-separated list of slopes.
+fn parse_slopes(list: &str) -> Result<Vec<Slope>, String> {
+    return list.split_whitespace().map(|s| s.parse::<Slope>()).collect();
+}
+
+/// `aoc day3 --optimal [--most] [--max-right <n>] [--max-down <n>]`: lists
+/// the slopes hitting the fewest (or most) trees. The search defaults to the
+/// width and height of the map.
+fn optimal(args: &Args, map: &Map) -> Result<(), String> {
+    let objective = if args.flag("most") { Objective::Most } else { Objective::Fewest };
+    let max_right = args.value::<usize>("max-right")?.unwrap_or_else(|| map.width());
+    let max_down = args.value::<usize>("max-down")?.unwrap_or_else(|| map.height());
+
+    let search = map.optimal_slopes(max_right, max_down, objective);
+    println!(
+        "{} trees: {} on {} slope(s)",
+        if objective == Objective::Most { "Most" } else { "Fewest" },
+        search.trees,
+        search.slopes.len()
+    );
+    for slope in &search.slopes {
+        println!("  {}", slope);
+    }
+    return Ok(());
+}
+
+/// `aoc day3 --render <slope> [--image <path.svg|path.ppm>] [--scale <n>]`:
+/// prints the map with the path taken on `slope` drawn over it, and writes
+/// it as an image if asked to.
+fn render(args: &Args, map: &Map, slope: Slope) -> Result<(), String> {
+    let overlay = map.path_overlay(slope);
+    if let Some(image) = args.value::<String>("image")? {
+        let scale = args.value::<usize>("scale")?.unwrap_or(4);
+        let data = match Path::new(&image).extension().and_then(|e| e.to_str()) {
+            Some("svg") => day3::to_svg(&overlay, scale).into_bytes(),
+            Some("ppm") => day3::to_ppm(&overlay, scale),
+            _ => return Err(format!("{}: images must be .svg or .ppm", image)),
+        };
+        fs::write(&image, data).map_err(|e| format!("{}: {}", image, e))?;
+    }
+
+    print!("{}", overlay);
+    return Ok(());
+}
+
+/// Reads a map drawn with a legend other than the puzzle's own.
+fn load_with_legend(input: &Input, legend: Legend) -> Result<Map, String> {
+    let text = input.read(Map::EXAMPLES).map_err(|e| describe(e, input))?;
+    let lines: Vec<String> = text.lines().map(String::from).collect();
+    return Map::from_strings_with_legend(&lines, legend).map_err(|e| describe(Error::from(e), input));
+}
+
+/// A cost, or that it's too big to work out.
+fn show_cost(cost: Option<u64>) -> String {
+    match cost {
+        Some(cost) => return cost.to_string(),
+        None => return String::from("overflow"),
+    }
+}
+
+/// `aoc day3 --costs [--slopes ...]`: totals the terrain landed on along
+/// each slope, and what it costs.
+fn costs(map: &Map, slopes: &[Slope]) {
+    for &slope in slopes {
+        let traversal = map.traverse(slope);
+        println!("Slope {}: total cost {}", slope, show_cost(traversal.total_cost));
+        for (terrain, squares, cost) in traversal.terrains {
+            println!("  {:<10} {:>6} squares {:>8} cost", terrain.name, squares, show_cost(cost));
+        }
+    }
+}
+
+/// `aoc day3 [--slopes "<right>,<down> <n>/<d> ..."]`: counts the trees hit
+/// on each slope, and their product.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = days::find(3).unwrap();
+    let input = select_input(args, day)?;
+    let known = [
+        "input",
+        "example",
+        "slopes",
+        "optimal",
+        "most",
+        "max-right",
+        "max-down",
+        "render",
+        "image",
+        "scale",
+        "legend",
+        "costs",
+    ];
+    let map = match args.value::<String>("legend")? {
+        Some(legend) => load_with_legend(&input, legend.parse().map_err(|e| format!("--legend: {}", e))?)?,
+        None => Map::from_input(&input, &args.options(&known)).map_err(|e| describe(e, &input))?,
+    };
+
+    if args.flag("optimal") {
+        return optimal(args, &map);
+    }
+    if let Some(slope) = args.value::<Slope>("render")? {
+        return render(args, &map, slope);
+    }
+
+    let slopes = match args.value::<String>("slopes")? {
+        Some(list) => parse_slopes(&list)?,
+        None => PUZZLE_SLOPES.to_vec(),
+    };
+
+    if args.flag("costs") {
+        costs(&map, &slopes);
+        return Ok(());
+    }
+
+    for &slope in &slopes {
+        println!("Slope {}: {} trees", slope, map.count_trees(slope));
+    }
+    match map.product_of_trees(&slopes) {
+        Some(product) => println!("Product: {}", product),
+        None => return Err(String::from("the product of trees overflows a 64-bit integer")),
+    }
+    return Ok(());
+}
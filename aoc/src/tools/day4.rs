@@ -0,0 +1,145 @@
+use crate::args::Args;
+use crate::days;
+use crate::tools::csv_field;
+use crate::{describe, select_input};
+use common::{Error, Solution};
+use day4::{Layout, ParseMode, PassportBatch, Record, Schema, Validator};
+use serde_json::{Map, Value};
+
+/// A passport's fields as a JSON object, in canonical order.
+fn json_object(record: &Record, schema: &Schema, strip_invalid: bool) -> Value {
+    let fields: Map<String, Value> = record
+        .canonical_fields(schema, strip_invalid)
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), Value::from(value)))
+        .collect();
+    return Value::Object(fields);
+}
+
+/// One JSON object per passport, holding its fields.
+fn print_jsonl(batch: &PassportBatch, strip_invalid: bool) {
+    for record in batch.records() {
+        println!("{}", json_object(record, batch.schema(), strip_invalid));
+    }
+}
+
+/// One column per field in the schema; other fields are left out.
+fn print_csv(batch: &PassportBatch, strip_invalid: bool) {
+    let names: Vec<&str> = batch.schema().fields.iter().map(|rule| rule.name.as_str()).collect();
+    println!("{}", names.iter().map(|name| csv_field(name)).collect::<Vec<_>>().join(","));
+    for record in batch.records() {
+        let fields = record.canonical_fields(batch.schema(), strip_invalid);
+        let row: Vec<String> = names
+            .iter()
+            .map(|name| match fields.iter().find(|(key, _)| key == name) {
+                Some((_, value)) => csv_field(value),
+                None => String::new(),
+            })
+            .collect();
+        println!("{}", row.join(","));
+    }
+}
+
+/// `aoc day4 [--part 1|2] [--schema <path>] [--required <fields>]
+/// [--optional <fields>] [--strict | --count]`: lists every passport that fails part 1
+/// (missing a required field) or part 2 (any validation rule), with where it
+/// is in the batch and every reason it fails. `--strict` first rejects
+/// batches with duplicate keys, unknown fields or malformed tokens. `--count`
+/// only counts the complete and valid passports, reading the batch a line at a
+/// time rather than keeping it in memory.
+///
+/// `--normalise [--layout line|lines]` instead writes the batch back out with
+/// its fields in a canonical order, and `--export csv|jsonl` converts it.
+/// Either can `--strip-invalid` fields, which also drops fields the schema
+/// doesn't know.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = days::find(4).unwrap();
+    let input = select_input(args, day)?;
+    let part = args.value::<u32>("part")?.unwrap_or(2);
+    if part != 1 && part != 2 {
+        return Err(format!("there is no part {}", part));
+    }
+
+    let mut schema = match args.value::<String>("schema")? {
+        Some(path) => Schema::from_file(&path)?,
+        None => Schema::default(),
+    };
+    let known = [
+        "input",
+        "example",
+        "part",
+        "schema",
+        "strict",
+        "count",
+        "normalise",
+        "layout",
+        "export",
+        "strip-invalid",
+    ];
+    schema.configure(&args.options(&known)).map_err(|e| e.to_string())?;
+
+    if args.flag("count") {
+        let reader = input.reader(PassportBatch::EXAMPLES).map_err(|e| describe(e, &input))?;
+        let (complete, valid) = Validator::new(schema)?
+            .count(reader)
+            .map_err(|e| describe(Error::from(e), &input))?;
+        println!("Complete: {}", complete);
+        println!("Valid: {}", valid);
+        return Ok(());
+    }
+    let mode = if args.flag("strict") { ParseMode::Strict } else { ParseMode::Lenient };
+
+    let text = input.read(PassportBatch::EXAMPLES).map_err(|e| describe(e, &input))?;
+    let batch = PassportBatch::parse_with(&text, mode, schema).map_err(|errors| {
+        let described: Vec<String> = errors.into_iter().map(|e| describe(Error::from(e), &input)).collect();
+        return described.join("\n");
+    })?;
+
+    let strip_invalid = args.flag("strip-invalid");
+    if args.flag("normalise") {
+        let layout = args.value::<Layout>("layout")?.unwrap_or(Layout::Line);
+        print!("{}", batch.normalise(layout, strip_invalid));
+        return Ok(());
+    }
+    match args.value::<String>("export")?.as_deref() {
+        Some("csv") => {
+            print_csv(&batch, strip_invalid);
+            return Ok(());
+        }
+        Some("jsonl") => {
+            print_jsonl(&batch, strip_invalid);
+            return Ok(());
+        }
+        Some(other) => return Err(format!("unknown export format '{}'", other)),
+        None => (),
+    }
+
+    let rejections = if part == 1 { batch.incomplete() } else { batch.rejections() };
+    for rejection in &rejections {
+        let record = rejection.record;
+        let lines = if record.first_line == record.last_line {
+            format!("line {}", record.first_line)
+        } else {
+            format!("lines {}-{}", record.first_line, record.last_line)
+        };
+        println!("Record {} ({}):", rejection.index + 1, lines);
+        for violation in &rejection.violations {
+            println!("  {}", violation);
+        }
+    }
+    let failed = if part == 1 { "incomplete" } else { "invalid" };
+    println!("{} of {} passports are {}", rejections.len(), batch.records().len(), failed);
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_keeps_field_order() {
+        let batch = PassportBatch::parse("pid:1 cid:2 byr:1980 zzz:3 ecl:grn\n").unwrap();
+        let json = json_object(&batch.records()[0], batch.schema(), false);
+        assert_eq!(json.to_string(), r#"{"byr":"1980","ecl":"grn","pid":"1","cid":"2","zzz":"3"}"#);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::args::Args;
+use crate::days;
+use crate::{describe, select_input};
+use common::Solution;
+use day5::{BoardingPass, BoardingPasses, Seat};
+
+/// `aoc day5 [--encode <seat id>]`: decodes every boarding pass, then shows
+/// the highest seat ID and the missing seat. `--encode` instead shows the code
+/// for a seat.
+pub fn run(args: &Args) -> Result<(), String> {
+    if let Some(id) = args.value::<u16>("encode")? {
+        let seat = Seat::from_id(id).ok_or(format!("no seat has ID {}", id))?;
+        println!("{}", BoardingPass::encode(seat));
+        return Ok(());
+    }
+
+    let day = days::find(5).unwrap();
+    let input = select_input(args, day)?;
+    let passes = BoardingPasses::from_input(&input, &args.options(&["input", "example", "encode"]))
+        .map_err(|e| describe(e, &input))?;
+
+    for pass in passes.passes() {
+        println!(
+            "{}: row {:>3}, column {}, seat ID {:>4}",
+            pass.code,
+            pass.seat.row(),
+            pass.seat.column(),
+            pass.seat.id()
+        );
+    }
+    println!("Highest seat ID: {}", passes.highest_id());
+    match passes.missing_seat() {
+        Some(seat) => println!("Missing seat: row {}, column {}, seat ID {}", seat.row(), seat.column(), seat.id()),
+        None => println!("Missing seat: none"),
+    }
+    return Ok(());
+}
@@ -0,0 +1,18 @@
+use crate::args::Args;
+use crate::days;
+use crate::{describe, select_input};
+use common::{Error, Solution};
+use day6::CustomsForms;
+
+/// `aoc day6`: counts the questions that anyone and that everyone in each
+/// group answered yes to, reading the input a group at a time rather than
+/// all at once.
+pub fn run(args: &Args) -> Result<(), String> {
+    let day = days::find(6).unwrap();
+    let input = select_input(args, day)?;
+    let reader = input.reader(CustomsForms::EXAMPLES).map_err(|e| describe(e, &input))?;
+    let forms = CustomsForms::from_reader(reader).map_err(|e| describe(Error::from(e), &input))?;
+    println!("Anyone: {}", forms.part1());
+    println!("Everyone: {}", forms.part2());
+    return Ok(());
+}
@@ -0,0 +1,51 @@
+//! Day-specific subcommands that go beyond printing the two answers.
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+
+use std::str::FromStr;
+
+/// How a tool should lay out a report.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Format, ()> {
+        match s {
+            "table" => return Ok(Format::Table),
+            "csv" => return Ok(Format::Csv),
+            "json" => return Ok(Format::Json),
+            _ => return Err(()),
+        }
+    }
+}
+
+/// Quotes a CSV field if it needs it.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+    return field.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
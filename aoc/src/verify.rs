@@ -0,0 +1,132 @@
+use crate::args::Args;
+use crate::days::{self, Day};
+use crate::describe;
+use common::Options;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+use toml::Value;
+
+/// The accepted answers for each day's checked-in input, keyed by day and
+/// part, e.g. `[day8] part2 = 1056`.
+pub struct Answers {
+    table: Value,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let table = text.parse::<Value>().map_err(|e| e.to_string())?;
+        return Ok(Answers { table: table });
+    }
+
+    pub fn default_path() -> PathBuf {
+        return PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("answers.toml");
+    }
+
+    /// Answers may be written as strings or integers.
+    pub fn expected(&self, day: u32, part: u32) -> Option<String> {
+        let value = self
+            .table
+            .get(format!("day{}", day))?
+            .get(format!("part{}", part))?;
+        match value {
+            Value::String(s) => return Some(s.clone()),
+            other => return Some(other.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+fn check(answers: &Answers, day: u32, part: u32, actual: &str) -> Outcome {
+    match answers.expected(day, part) {
+        None => return Outcome::Missing,
+        Some(expected) if expected == actual => return Outcome::Pass,
+        Some(expected) => return Outcome::Fail(expected),
+    }
+}
+
+/// Runs one day on its checked-in input, printing a line per part. Returns
+/// whether every part with a known answer matched it.
+fn verify_day(day: &Day, answers: &Answers) -> bool {
+    let input = day.default_input();
+    let start = Instant::now();
+    let solution = match input
+        .read(day.examples)
+        .and_then(|text| (day.parse)(&text, &Options::new()))
+    {
+        Ok(solution) => solution,
+        Err(err) => {
+            println!("Day {:>2}: FAIL {}", day.number, describe(err, &input));
+            return false;
+        }
+    };
+    println!("Day {:>2} parse: [{:.2?}]", day.number, start.elapsed());
+
+    let mut ok = true;
+    for part in 1..=2 {
+        let start = Instant::now();
+        let actual = match part {
+            1 => solution.part1(),
+            _ => solution.part2(),
+        };
+        let elapsed = start.elapsed();
+
+        let status = match check(answers, day.number, part, &actual) {
+            Outcome::Pass => format!("PASS    {}", actual),
+            Outcome::Missing => format!("MISSING {}", actual),
+            Outcome::Fail(expected) => {
+                ok = false;
+                format!("FAIL    {} (expected {})", actual, expected)
+            }
+        };
+        println!("Day {:>2} part {}: {} [{:.2?}]", day.number, part, status, elapsed);
+    }
+
+    return ok;
+}
+
+/// `aoc verify [--day <n>] [--answers <path>]`
+pub fn verify(args: &Args) -> Result<(), String> {
+    let path = args.value::<PathBuf>("answers")?.unwrap_or_else(Answers::default_path);
+    let text = fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let answers = Answers::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let selected: Vec<&Day> = match args.value::<u32>("day")? {
+        Some(number) => vec![days::find(number).ok_or(format!("no solver for day {}", number))?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut failures = 0;
+    for day in selected {
+        if !verify_day(day, &answers) {
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} day(s) failed verification", failures));
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outcomes() {
+        let answers = Answers::parse("[day1]\npart1 = 514579\npart2 = \"241861950\"\n").unwrap();
+        assert_eq!(check(&answers, 1, 1, "514579"), Outcome::Pass);
+        assert_eq!(check(&answers, 1, 2, "241861950"), Outcome::Pass);
+        assert_eq!(check(&answers, 1, 1, "1"), Outcome::Fail(String::from("514579")));
+        assert_eq!(check(&answers, 2, 1, "1"), Outcome::Missing);
+    }
+}
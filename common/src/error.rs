@@ -0,0 +1,212 @@
+use crate::options::OptionError;
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseErrorKind {
+    /// The text doesn't have the expected shape; holds a description of what
+    /// was expected.
+    Syntax(String),
+    /// A numeric field couldn't be parsed.
+    InvalidNumber,
+    /// A keyword or symbol that the parser doesn't know about.
+    Unrecognized(String),
+    /// There was nothing to parse.
+    Empty,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::Syntax(expected) => return write!(f, "expected {}", expected),
+            ParseErrorKind::InvalidNumber => return write!(f, "invalid number"),
+            ParseErrorKind::Unrecognized(what) => return write!(f, "unrecognized {}", what),
+            ParseErrorKind::Empty => return write!(f, "empty input"),
+        }
+    }
+}
+
+/// An error in puzzle input, with enough location information to point at
+/// the offending text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub file: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// The full text of the offending line.
+    pub line_text: String,
+    /// Length of the offending text, in characters.
+    pub len: usize,
+}
+
+impl ParseError {
+    /// An error covering `offending`, which must be a slice of `line`.
+    /// `line_number` is 1-based.
+    pub fn at(line_number: usize, line: &str, offending: &str, kind: ParseErrorKind) -> ParseError {
+        let start = offending.as_ptr() as usize;
+        let line_start = line.as_ptr() as usize;
+        let offset = if start >= line_start && start + offending.len() <= line_start + line.len() {
+            start - line_start
+        } else {
+            0
+        };
+
+        return ParseError {
+            kind: kind,
+            file: None,
+            line: line_number,
+            column: line[..offset].chars().count() + 1,
+            line_text: line.to_string(),
+            len: offending.chars().count(),
+        };
+    }
+
+    /// An error covering the whole of `line`.
+    pub fn line(line_number: usize, line: &str, kind: ParseErrorKind) -> ParseError {
+        return ParseError::at(line_number, line, line, kind);
+    }
+
+    /// An error that isn't tied to any particular text, such as empty input.
+    pub fn input(kind: ParseErrorKind) -> ParseError {
+        return ParseError::line(1, "", kind);
+    }
+
+    pub fn with_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        return self;
+    }
+
+    /// The text that the error refers to.
+    pub fn offending(&self) -> String {
+        return self
+            .line_text
+            .chars()
+            .skip(self.column - 1)
+            .take(self.len)
+            .collect();
+    }
+
+    /// Renders the error as a multi-line diagnostic, with carets under the
+    /// offending text.
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent: String = self
+            .line_text
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        let mut result = format!("error: {}\n", self.kind);
+        result += &format!(
+            "{}--> {}:{}:{}\n",
+            gutter,
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column
+        );
+        result += &format!("{} |\n", gutter);
+        result += &format!("{} | {}\n", number, self.line_text);
+        result += &format!("{} | {}{}", gutter, indent, "^".repeat(self.len.max(1)));
+        return result;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        write!(f, "{}:{}:{}: {}", file, self.line, self.column, self.kind)?;
+        if self.len > 0 {
+            write!(f, " `{}`", self.offending())?;
+        }
+        return Ok(());
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Anything that can go wrong loading a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    Option(OptionError),
+    /// The named example doesn't exist; holds the names that do.
+    UnknownExample(String, Vec<&'static str>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => return write!(f, "{}", err),
+            Error::Parse(err) => return write!(f, "{}", err),
+            Error::Option(err) => return write!(f, "{}", err),
+            Error::UnknownExample(name, available) => {
+                return write!(f, "unknown example '{}' (available: {})", name, available.join(", "));
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => return Some(err),
+            Error::Parse(err) => return Some(err),
+            Error::Option(err) => return Some(err),
+            Error::UnknownExample(_, _) => return None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        return Error::Io(err);
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        return Error::Parse(err);
+    }
+}
+
+impl From<OptionError> for Error {
+    fn from(err: OptionError) -> Error {
+        return Error::Option(err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let line = "jmp +x4";
+        let err = ParseError::at(3, line, &line[4..], ParseErrorKind::InvalidNumber);
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 5);
+        assert_eq!(err.offending(), "+x4");
+        assert_eq!(err.with_file("input").to_string(), "input:3:5: invalid number `+x4`");
+    }
+
+    #[test]
+    fn render() {
+        let line = "foo +1";
+        let err = ParseError::at(12, line, &line[..3], ParseErrorKind::Unrecognized("operation".to_string()))
+            .with_file("day8/input");
+        let expected = "\
+error: unrecognized operation
+  --> day8/input:12:1
+   |
+12 | foo +1
+   | ^^^";
+        assert_eq!(err.render(), expected);
+    }
+}
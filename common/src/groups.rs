@@ -0,0 +1,101 @@
+use std::io::{self, BufRead};
+
+/// A run of consecutive non-blank lines, such as one passport or one group's
+/// customs answers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineGroup {
+    /// 1-based line number of the first line.
+    pub first_line: usize,
+    /// The lines, without their line endings.
+    pub lines: Vec<String>,
+}
+
+impl LineGroup {
+    pub fn last_line(&self) -> usize {
+        return self.first_line + self.lines.len() - 1;
+    }
+}
+
+/// Reads blank-line separated groups of lines one at a time.
+pub struct LineGroups<R> {
+    reader: R,
+    line_number: usize,
+}
+
+/// Splits `reader` into groups of lines. Lines may end with `\n` or `\r\n`,
+/// and lines holding only whitespace count as blank.
+pub fn line_groups<R: BufRead>(reader: R) -> LineGroups<R> {
+    return LineGroups {
+        reader: reader,
+        line_number: 0,
+    };
+}
+
+impl<R: BufRead> Iterator for LineGroups<R> {
+    type Item = io::Result<LineGroup>;
+
+    fn next(&mut self) -> Option<io::Result<LineGroup>> {
+        let mut group: Option<LineGroup> = None;
+        loop {
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Err(err) => return Some(Err(err)),
+                Ok(0) => return group.map(Ok),
+                Ok(_) => (),
+            }
+            self.line_number += 1;
+
+            if line.trim().is_empty() {
+                if group.is_some() {
+                    return group.map(Ok);
+                }
+                continue;
+            }
+
+            if line.ends_with('\n') {
+                line.pop();
+            }
+            if line.ends_with('\r') {
+                line.pop();
+            }
+            let first_line = self.line_number;
+            group
+                .get_or_insert_with(|| LineGroup {
+                    first_line: first_line,
+                    lines: vec![],
+                })
+                .lines
+                .push(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups() {
+        let input = "a b\r\nc\r\n\r\n  \n\nd\n \t\ne";
+        let groups: Vec<LineGroup> = line_groups(input.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(
+            groups,
+            vec![
+                LineGroup {
+                    first_line: 1,
+                    lines: vec![String::from("a b"), String::from("c")],
+                },
+                LineGroup {
+                    first_line: 6,
+                    lines: vec![String::from("d")],
+                },
+                LineGroup {
+                    first_line: 8,
+                    lines: vec![String::from("e")],
+                },
+            ]
+        );
+        assert_eq!(groups[0].last_line(), 2);
+        assert_eq!(line_groups("\n \n".as_bytes()).count(), 0);
+    }
+}
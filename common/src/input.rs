@@ -0,0 +1,121 @@
+use crate::error::Error;
+use crate::options::Options;
+use std::convert::Infallible;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// A small puzzle input shipped with a solver, usually taken from the puzzle
+/// description.
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    /// Options that the puzzle description uses for this example, where they
+    /// differ from the real input's.
+    pub options: &'static [(&'static str, &'static str)],
+}
+
+/// Where to read puzzle input from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Input {
+    Path(PathBuf),
+    Stdin,
+    Example(String),
+}
+
+impl Input {
+    /// A name for the input, for use in diagnostics.
+    pub fn name(&self) -> String {
+        match self {
+            Input::Path(path) => return path.display().to_string(),
+            Input::Stdin => return String::from("<stdin>"),
+            Input::Example(name) => return format!("<example {}>", name),
+        }
+    }
+
+    fn example<'a>(&self, examples: &'a [Example]) -> Result<Option<&'a Example>, Error> {
+        match self {
+            Input::Example(name) => match examples.iter().find(|e| e.name == name) {
+                Some(example) => return Ok(Some(example)),
+                None => {
+                    let available = examples.iter().map(|e| e.name).collect();
+                    return Err(Error::UnknownExample(name.clone(), available));
+                }
+            },
+            _ => return Ok(None),
+        }
+    }
+
+    /// Reads the whole input, looking up named examples in `examples`.
+    pub fn read(&self, examples: &[Example]) -> Result<String, Error> {
+        match self {
+            Input::Path(path) => return Ok(fs::read_to_string(path)?),
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                return Ok(text);
+            }
+            Input::Example(_) => return Ok(self.example(examples)?.unwrap().input.to_string()),
+        }
+    }
+
+    /// Opens the input for reading a bit at a time, for callers that don't
+    /// need all of it at once.
+    pub fn reader(&self, examples: &[Example]) -> Result<Box<dyn BufRead>, Error> {
+        match self {
+            Input::Path(path) => return Ok(Box::new(BufReader::new(File::open(path)?))),
+            Input::Stdin => return Ok(Box::new(io::stdin().lock())),
+            Input::Example(_) => return Ok(Box::new(self.example(examples)?.unwrap().input.as_bytes())),
+        }
+    }
+
+    /// The options that go with this input: an example's own options, or none.
+    pub fn options(&self, examples: &[Example]) -> Result<Options, Error> {
+        return Ok(match self.example(examples)? {
+            Some(example) => example.options.iter().cloned().collect(),
+            None => Options::new(),
+        });
+    }
+}
+
+/// `-` means stdin, anything else is a path.
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Input, Infallible> {
+        if s == "-" {
+            return Ok(Input::Stdin);
+        }
+        return Ok(Input::Path(PathBuf::from(s)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[Example] = &[Example {
+        name: "small",
+        input: "1\n2\n",
+        options: &[("window", "2")],
+    }];
+
+    #[test]
+    fn from_str() {
+        assert_eq!("-".parse::<Input>(), Ok(Input::Stdin));
+        assert_eq!("day1/input".parse::<Input>(), Ok(Input::Path(PathBuf::from("day1/input"))));
+    }
+
+    #[test]
+    fn examples() {
+        let input = Input::Example(String::from("small"));
+        assert_eq!(input.read(EXAMPLES).unwrap(), "1\n2\n");
+        let lines: Vec<String> = input.reader(EXAMPLES).unwrap().lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["1", "2"]);
+        assert_eq!(input.options(EXAMPLES).unwrap().get::<usize>("window"), Ok(Some(2)));
+
+        let missing = Input::Example(String::from("large"));
+        assert_eq!(missing.read(EXAMPLES).unwrap_err().to_string(), "unknown example 'large' (available: small)");
+    }
+}
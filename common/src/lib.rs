@@ -0,0 +1,13 @@
+mod error;
+mod groups;
+mod input;
+mod options;
+mod parse;
+mod solution;
+
+pub use error::{Error, ParseError, ParseErrorKind};
+pub use groups::{line_groups, LineGroup, LineGroups};
+pub use input::{Example, Input};
+pub use options::{OptionError, Options};
+pub use parse::parse_numbers;
+pub use solution::{parse_boxed, DynSolution, Solution};
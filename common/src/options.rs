@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+/// Day-specific settings, such as day 9's preamble length, given as
+/// `name = value` pairs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Options {
+    values: HashMap<String, String>,
+}
+
+impl Options {
+    pub fn new() -> Options {
+        return Options::default();
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// Returns these options overridden by any that are also set in `other`.
+    pub fn merged(&self, other: &Options) -> Options {
+        let mut result = self.clone();
+        for (name, value) in &other.values {
+            result.set(name, value);
+        }
+        return result;
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, OptionError> {
+        match self.values.get(name) {
+            None => return Ok(None),
+            Some(value) => {
+                return value.parse::<T>().map(Some).map_err(|_| OptionError {
+                    name: name.to_string(),
+                    message: format!("invalid value '{}'", value),
+                });
+            }
+        }
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for Options {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a str)>>(iter: I) -> Options {
+        let mut options = Options::new();
+        for (name, value) in iter {
+            options.set(name, value);
+        }
+        return options;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionError {
+    pub name: String,
+    pub message: String,
+}
+
+impl fmt::Display for OptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "option '{}': {}", self.name, self.message);
+    }
+}
+
+impl error::Error for OptionError {}
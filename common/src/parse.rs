@@ -0,0 +1,31 @@
+use crate::error::{ParseError, ParseErrorKind};
+use std::str::FromStr;
+
+/// Parses input with one number per line.
+pub fn parse_numbers<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError> {
+    return input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let value = line.trim();
+            return value
+                .parse::<T>()
+                .map_err(|_| ParseError::at(idx + 1, line, value, ParseErrorKind::InvalidNumber));
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_numbers::<i32>("1\n-2\r\n 3 \n"), Ok(vec![1, -2, 3]));
+
+        let err = parse_numbers::<i32>("1\n2\n  x3\n").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.offending(), "x3");
+    }
+}
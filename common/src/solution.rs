@@ -0,0 +1,64 @@
+use crate::error::{Error, ParseError};
+use crate::input::{Example, Input};
+use crate::options::{OptionError, Options};
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// A day's puzzle: parses the raw input into a typed model, and answers both
+/// parts from it.
+pub trait Solution: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Examples from the puzzle description.
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    /// Applies day-specific options to a parsed model.
+    fn configure(&mut self, _options: &Options) -> Result<(), OptionError> {
+        return Ok(());
+    }
+
+    fn part1(&self) -> Self::Part1;
+
+    fn part2(&self) -> Self::Part2;
+
+    /// Reads, parses and configures a model. Options given here take
+    /// precedence over an example's own options.
+    fn from_input(input: &Input, options: &Options) -> Result<Self, Error> {
+        let text = input.read(Self::EXAMPLES)?;
+        let mut solution = Self::parse(&text).map_err(|e| e.with_file(&input.name()))?;
+        solution.configure(&input.options(Self::EXAMPLES)?.merged(options))?;
+        return Ok(solution);
+    }
+
+    fn from_file(filename: &str) -> Result<Self, Error> {
+        return Self::from_input(&Input::Path(PathBuf::from(filename)), &Options::new());
+    }
+}
+
+/// Object-safe view of a parsed `Solution`, so that callers can treat every
+/// day the same way regardless of its model and answer types.
+pub trait DynSolution {
+    fn part1(&self) -> String;
+
+    fn part2(&self) -> String;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn part1(&self) -> String {
+        return Solution::part1(self).to_string();
+    }
+
+    fn part2(&self) -> String {
+        return Solution::part2(self).to_string();
+    }
+}
+
+/// Parses and configures `S` from already-read input.
+pub fn parse_boxed<S: Solution + 'static>(input: &str, options: &Options) -> Result<Box<dyn DynSolution>, Error> {
+    let mut solution = S::parse(input)?;
+    solution.configure(options)?;
+    return Ok(Box::new(solution));
+}
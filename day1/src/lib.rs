@@ -0,0 +1,588 @@
+use common::{parse_numbers, Example, OptionError, Options, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+/// For k of this or more, `find_k_sum` switches from sorting and narrowing
+/// with two pointers, which is O(n^(k-1)), to meeting in the middle, which is
+/// O(n^(k/2)) time and memory.
+const MEET_IN_THE_MIDDLE_MIN_K: usize = 4;
+
+/// The most cells `count_k_sums` allocates for its table of sums. Wider
+/// ranges of sums are counted another way.
+const MAX_COUNT_TABLE_CELLS: u128 = 1 << 22;
+
+/// Up to this k, wide ranges of sums are counted by joining on pair sums, in
+/// at most O(n^2) time; past it, only the sums that can be made are tracked.
+const MAX_JOINED_COUNT_K: usize = 4;
+
+/// Calls `f` with each k-combination of `0..n`, in lexicographic order, until
+/// `f` returns false.
+fn for_each_combination<F: FnMut(&[usize]) -> bool>(n: usize, k: usize, mut f: F) {
+    if k > n {
+        return;
+    }
+
+    let mut combo: Vec<usize> = (0..k).collect();
+    loop {
+        if !f(&combo) {
+            return;
+        }
+
+        // Find the rightmost index that can still move right, move it, and
+        // pack everything after it up against it.
+        let mut i = k;
+        while i > 0 && combo[i - 1] == n - k + i - 1 {
+            i -= 1;
+        }
+        if i == 0 {
+            return;
+        }
+
+        combo[i - 1] += 1;
+        for j in i..k {
+            combo[j] = combo[j - 1] + 1;
+        }
+    }
+}
+
+/// `order` holds indices into `entries`, sorted by value.
+fn find_sorted(entries: &[i64], order: &[usize], k: usize, target: i128) -> Option<Vec<usize>> {
+    match k {
+        0 => {
+            if target == 0 {
+                return Some(vec![]);
+            }
+            return None;
+        }
+        1 => {
+            return order
+                .iter()
+                .find(|&&i| entries[i] as i128 == target)
+                .map(|&i| vec![i]);
+        }
+        2 => {
+            if order.is_empty() {
+                return None;
+            }
+
+            let mut lo = 0;
+            let mut hi = order.len() - 1;
+            while lo < hi {
+                let sum = entries[order[lo]] as i128 + entries[order[hi]] as i128;
+                if sum == target {
+                    return Some(vec![order[lo], order[hi]]);
+                } else if sum < target {
+                    lo += 1;
+                } else {
+                    hi -= 1;
+                }
+            }
+            return None;
+        }
+        _ => {
+            for (pos, &i) in order.iter().enumerate() {
+                // An entry with the same value as the last one can't succeed
+                // where that one failed.
+                if pos > 0 && entries[order[pos - 1]] == entries[i] {
+                    continue;
+                }
+
+                let remainder = target - entries[i] as i128;
+                if let Some(mut result) = find_sorted(entries, &order[pos + 1..], k - 1, remainder) {
+                    result.push(i);
+                    return Some(result);
+                }
+            }
+            return None;
+        }
+    }
+}
+
+/// Every k-subset {a < b < c < ...} splits into a left half and a right half
+/// with every left index below every right index, so for each left-half sum
+/// we only need to keep the combination that ends earliest.
+fn meet_in_the_middle(entries: &[i64], k: usize, target: i128) -> Option<Vec<usize>> {
+    let sum = |combo: &[usize]| combo.iter().map(|&i| entries[i] as i128).sum::<i128>();
+    let left_k = k / 2;
+    let right_k = k - left_k;
+
+    let mut left: HashMap<i128, Vec<usize>> = HashMap::new();
+    for_each_combination(entries.len(), left_k, |combo| {
+        let existing = left.entry(sum(combo)).or_insert_with(|| combo.to_vec());
+        if combo.last() < existing.last() {
+            *existing = combo.to_vec();
+        }
+        return true;
+    });
+
+    let mut result = None;
+    for_each_combination(entries.len(), right_k, |combo| {
+        if let Some(candidate) = left.get(&(target - sum(combo))) {
+            if candidate.last().is_none_or(|&last| last < combo[0]) {
+                result = Some([candidate.as_slice(), combo].concat());
+                return false;
+            }
+        }
+        return true;
+    });
+
+    return result;
+}
+
+/// Finds `k` entries at distinct positions that sum to `target`, returning
+/// their indices in ascending order. An entry is never paired with itself,
+/// though equal entries at different positions may be used together.
+pub fn find_k_sum(entries: &[i64], k: usize, target: i64) -> Option<Vec<usize>> {
+    if k > entries.len() {
+        return None;
+    }
+
+    let mut result = if k >= MEET_IN_THE_MIDDLE_MIN_K {
+        meet_in_the_middle(entries, k, target as i128)?
+    } else {
+        let mut order: Vec<usize> = (0..entries.len()).collect();
+        order.sort_by_key(|&i| entries[i]);
+        find_sorted(entries, &order, k, target as i128)?
+    };
+
+    result.sort();
+    return Some(result);
+}
+
+/// Collects every k-combination from `order` (indices into `entries`,
+/// sorted by value) that sums to `target`, each appended to `prefix`.
+fn find_all_sorted(
+    entries: &[i64],
+    order: &[usize],
+    k: usize,
+    target: i128,
+    prefix: &mut Vec<usize>,
+    results: &mut Vec<Vec<usize>>,
+) {
+    let value = |pos: usize| entries[order[pos]] as i128;
+
+    match k {
+        0 => {
+            if target == 0 {
+                results.push(prefix.clone());
+            }
+        }
+        2 => {
+            if order.is_empty() {
+                return;
+            }
+
+            // As in `find_sorted`, but when the sum matches, every pairing of
+            // the run of equal values at the bottom with the run at the top
+            // is a solution.
+            let mut lo = 0;
+            let mut hi = order.len() - 1;
+            while lo < hi {
+                let sum = value(lo) + value(hi);
+                if sum < target {
+                    lo += 1;
+                } else if sum > target {
+                    hi -= 1;
+                } else if value(lo) == value(hi) {
+                    for a in lo..hi {
+                        for b in a + 1..=hi {
+                            results.push([prefix.as_slice(), &[order[a], order[b]]].concat());
+                        }
+                    }
+                    return;
+                } else {
+                    let mut lo_end = lo;
+                    while value(lo_end + 1) == value(lo) {
+                        lo_end += 1;
+                    }
+                    let mut hi_start = hi;
+                    while value(hi_start - 1) == value(hi) {
+                        hi_start -= 1;
+                    }
+
+                    for a in lo..=lo_end {
+                        for b in hi_start..=hi {
+                            results.push([prefix.as_slice(), &[order[a], order[b]]].concat());
+                        }
+                    }
+                    lo = lo_end + 1;
+                    hi = hi_start - 1;
+                }
+            }
+        }
+        _ => {
+            for pos in 0..order.len() {
+                if pos + k > order.len() {
+                    break;
+                }
+
+                // The smallest sum still possible is already too big.
+                let smallest: i128 = (pos..pos + k).map(value).sum();
+                if smallest > target {
+                    break;
+                }
+
+                prefix.push(order[pos]);
+                find_all_sorted(entries, &order[pos + 1..], k - 1, target - value(pos), prefix, results);
+                prefix.pop();
+            }
+        }
+    }
+}
+
+/// Finds every combination of `k` entries at distinct positions that sums
+/// to `target`. Each combination's indices are in ascending order, and the
+/// combinations are sorted.
+pub fn find_all_k_sums(entries: &[i64], k: usize, target: i64) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i]);
+
+    let mut results = vec![];
+    find_all_sorted(entries, &order, k, target as i128, &mut vec![], &mut results);
+    for result in results.iter_mut() {
+        result.sort();
+    }
+    results.sort();
+    return results;
+}
+
+/// Counts the combinations of `k` entries at distinct positions that sum to
+/// `target`, without listing them. Returns `None` if the count doesn't fit in
+/// a `u128`.
+///
+/// This is a subset-sum count over (number of entries chosen, sum), taking
+/// O(n * k * target) time. Since exactly `k` entries are chosen, every entry
+/// is first shifted up by the smallest entry so that sums are never negative.
+/// If that makes the table of sums too big, small k are counted by joining
+/// on the last two entries instead, and larger k by tracking only the sums
+/// that can actually be made.
+pub fn count_k_sums(entries: &[i64], k: usize, target: i64) -> Option<u128> {
+    if k == 0 {
+        return Some(if target == 0 { 1 } else { 0 });
+    }
+    if k > entries.len() {
+        return Some(0);
+    }
+    let min = match entries.iter().min() {
+        Some(&min) => min as i128,
+        None => return Some(0),
+    };
+
+    let shifted = target as i128 - min * k as i128;
+    if shifted < 0 {
+        return Some(0);
+    }
+    if (shifted as u128 + 1) * (k as u128 + 1) > MAX_COUNT_TABLE_CELLS {
+        let mut values: Vec<i128> = entries.iter().map(|&entry| entry as i128 - min).collect();
+        values.sort();
+        if k <= MAX_JOINED_COUNT_K {
+            return Some(count_k_sums_joined(&values, k, shifted));
+        }
+        return count_k_sums_sparse(&values, k, shifted);
+    }
+    let target = shifted as usize;
+
+    // ways[j][s]: the number of ways to choose j of the entries seen so far
+    // with a (shifted) sum of s.
+    let mut ways = vec![vec![0u128; target + 1]; k + 1];
+    ways[0][0] = 1;
+    for &entry in entries {
+        let entry = (entry as i128 - min) as usize;
+        if entry > target {
+            continue;
+        }
+
+        // Go downwards so that each entry is used at most once.
+        for j in (1..=k).rev() {
+            for s in (entry..=target).rev() {
+                let from = ways[j - 1][s - entry];
+                if from > 0 {
+                    ways[j][s] = ways[j][s].checked_add(from)?;
+                }
+            }
+        }
+    }
+
+    return Some(ways[k][target]);
+}
+
+/// As `count_k_sums`, over sorted, non-negative `values`. Pairs are counted
+/// with a hash map of the values before each one, and fours by matching each
+/// pair against a hash map of the pair sums before it, so that k = 2 and 3
+/// take O(n^(k-1)) time and k = 4 takes O(n^2). Any other k tries every
+/// choice of first value and counts the rest.
+fn count_k_sums_joined(values: &[i128], k: usize, target: i128) -> u128 {
+    match k {
+        1 => return values.iter().filter(|&&value| value == target).count() as u128,
+        2 => {
+            let mut seen: HashMap<i128, u128> = HashMap::new();
+            let mut count = 0;
+            for &value in values {
+                count += seen.get(&(target - value)).cloned().unwrap_or(0);
+                *seen.entry(value).or_insert(0) += 1;
+            }
+            return count;
+        }
+        4 => {
+            // For each third value, complete the pairs of values before it
+            // with it and each value after it. Since the values are sorted,
+            // a pair that's too big is followed only by bigger ones.
+            let mut pairs: HashMap<i128, u128> = HashMap::new();
+            let mut count = 0;
+            for third in 0..values.len() {
+                for &fourth in &values[third + 1..] {
+                    let sum = values[third] + fourth;
+                    if sum > target {
+                        break;
+                    }
+                    count += pairs.get(&(target - sum)).cloned().unwrap_or(0);
+                }
+                for &first in &values[..third] {
+                    let sum = first + values[third];
+                    if sum > target {
+                        break;
+                    }
+                    *pairs.entry(sum).or_insert(0) += 1;
+                }
+            }
+            return count;
+        }
+        _ => {
+            let mut count = 0;
+            for pos in 0..values.len() {
+                // The smallest sum still possible is already too big.
+                if pos + k > values.len() || values[pos..pos + k].iter().sum::<i128>() > target {
+                    break;
+                }
+                count += count_k_sums_joined(&values[pos + 1..], k - 1, target - values[pos]);
+            }
+            return count;
+        }
+    }
+}
+
+/// As `count_k_sums`, over sorted, non-negative `values`, but keeping only
+/// the sums reached so far. Since no value is negative, sums above `target`
+/// can never come back down to it and are dropped.
+fn count_k_sums_sparse(values: &[i128], k: usize, target: i128) -> Option<u128> {
+    // ways[j]: sum -> the number of ways to choose j of the values seen so
+    // far with that sum.
+    let mut ways: Vec<HashMap<i128, u128>> = vec![HashMap::new(); k + 1];
+    ways[0].insert(0, 1);
+    for &value in values {
+        if value > target {
+            break;
+        }
+        for j in (1..=k).rev() {
+            let (below, above) = ways.split_at_mut(j);
+            for (&sum, &count) in &below[j - 1] {
+                if sum + value > target {
+                    continue;
+                }
+                let total = above[0].entry(sum + value).or_insert(0);
+                *total = total.checked_add(count)?;
+            }
+        }
+    }
+
+    return Some(ways[k].get(&target).cloned().unwrap_or(0));
+}
+
+/// The product of the entries summing to the target, or why there isn't one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Product {
+    Found(i64),
+    NoSolution,
+    Overflow,
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Product::Found(product) => return write!(f, "{}", product),
+            Product::NoSolution => return write!(f, "no solution"),
+            Product::Overflow => return write!(f, "product overflows a 64-bit integer"),
+        }
+    }
+}
+
+pub struct ExpenseReport {
+    entries: Vec<i64>,
+    target: i64,
+}
+
+impl ExpenseReport {
+    pub fn entries(&self) -> &[i64] {
+        return &self.entries;
+    }
+
+    pub fn target(&self) -> i64 {
+        return self.target;
+    }
+
+    fn product_of_k_sum(&self, k: usize) -> Product {
+        match find_k_sum(&self.entries, k, self.target) {
+            Some(indices) => {
+                let product = indices
+                    .iter()
+                    .try_fold(1i64, |acc, &i| acc.checked_mul(self.entries[i]));
+                return product.map_or(Product::Overflow, Product::Found);
+            }
+            None => return Product::NoSolution,
+        }
+    }
+}
+
+impl Solution for ExpenseReport {
+    type Part1 = Product;
+    type Part2 = Product;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "1721\n979\n366\n299\n675\n1456\n",
+        options: &[],
+    }];
+
+    fn parse(input: &str) -> Result<ExpenseReport, ParseError> {
+        let entries = parse_numbers(input)?;
+        return Ok(ExpenseReport {
+            entries: entries,
+            target: 2020,
+        });
+    }
+
+    /// `target`: the sum to look for, instead of 2020.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        if let Some(target) = options.get("target")? {
+            self.target = target;
+        }
+        return Ok(());
+    }
+
+    fn part1(&self) -> Product {
+        return self.product_of_k_sum(2);
+    }
+
+    fn part2(&self) -> Product {
+        return self.product_of_k_sum(3);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [i64; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(find_k_sum(&EXAMPLE, 2, 2020), Some(vec![0, 3]));
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(find_k_sum(&EXAMPLE, 3, 2020), Some(vec![1, 2, 4]));
+    }
+
+    #[test]
+    fn products() {
+        let report = ExpenseReport::parse(ExpenseReport::EXAMPLES[0].input).unwrap();
+        assert_eq!(report.part1(), Product::Found(514579));
+        assert_eq!(report.part2(), Product::Found(241861950));
+
+        let mut options = Options::new();
+        options.set("target", "1");
+        let mut unreachable = ExpenseReport::parse(ExpenseReport::EXAMPLES[0].input).unwrap();
+        unreachable.configure(&options).unwrap();
+        assert_eq!(unreachable.part1(), Product::NoSolution);
+        assert_eq!(unreachable.part1().to_string(), "no solution");
+
+        let huge = ExpenseReport::parse("4000000000\n-3999997980\n").unwrap();
+        assert_eq!(huge.part1(), Product::Overflow);
+    }
+
+    #[test]
+    fn wide_counts() {
+        // Too wide a range of sums for the table, so these are counted by
+        // joining.
+        assert_eq!(count_k_sums(&[-2000000000, 5, 2020], 2, 2025), Some(1));
+        assert_eq!(count_k_sums(&[-2000000000, 5, 2020, 2000002025], 2, 2025), Some(2));
+        assert_eq!(count_k_sums(&[1000000000, 1000000000, 1000000000, 7], 3, 3000000000), Some(1));
+        assert_eq!(count_k_sums(&EXAMPLE, 3, 3000000000), Some(0));
+
+        // Every way of counting agrees.
+        let entries = [-5, 3, 3, 8, 0, -2, 10, 3];
+        let mut values: Vec<i128> = entries.iter().map(|&entry| entry as i128 + 5).collect();
+        values.sort();
+        for k in 1..=5 {
+            for target in -25..40 {
+                let shifted = target as i128 + 5 * k as i128;
+                let expected = count_k_sums(&entries, k, target);
+                assert_eq!(count_k_sums_sparse(&values, k, shifted), expected, "k {} target {}", k, target);
+                if k <= MAX_JOINED_COUNT_K {
+                    assert_eq!(Some(count_k_sums_joined(&values, k, shifted)), expected, "k {} target {}", k, target);
+                }
+            }
+        }
+
+        // Thousands of entries spread over a wide range.
+        let entries: Vec<i64> = (0..1000).map(|i| i * 7919 % 1000003).collect();
+        let target = entries[10] + entries[500] + entries[900];
+        let expected = find_all_k_sums(&entries, 3, target).len() as u128;
+        assert!(expected > 0);
+        assert_eq!(count_k_sums(&entries, 3, target), Some(expected));
+        assert_eq!(count_k_sums(&[1000000000; 6], 4, 4000000000), Some(15));
+    }
+
+    #[test]
+    fn distinct_entries() {
+        assert_eq!(find_k_sum(&[1010, 5], 2, 2020), None);
+        assert_eq!(find_k_sum(&[1010, 5, 1010], 2, 2020), Some(vec![0, 2]));
+        assert_eq!(find_k_sum(&[1, 1, 1], 3, 3), Some(vec![0, 1, 2]));
+        assert_eq!(find_k_sum(&[1, 1], 3, 3), None);
+    }
+
+    #[test]
+    fn meet_in_the_middle() {
+        let entries = [7, 1, 1, 9, 3, 1, 4];
+        assert_eq!(find_k_sum(&entries, 4, 4), None);
+        assert_eq!(find_k_sum(&entries, 4, 6), Some(vec![1, 2, 4, 5]));
+        assert_eq!(find_k_sum(&entries, 7, 26), Some(vec![0, 1, 2, 3, 4, 5, 6]));
+
+        let found = find_k_sum(&entries, 5, 24).unwrap();
+        assert_eq!(found.len(), 5);
+        assert_eq!(found.iter().map(|&i| entries[i]).sum::<i64>(), 24);
+    }
+
+    #[test]
+    fn all_solutions() {
+        let sums = find_all_k_sums(&EXAMPLE, 2, 2020);
+        assert_eq!(sums, vec![vec![0, 3]]);
+
+        let sums = find_all_k_sums(&[1, 1, 1, 1], 2, 2);
+        assert_eq!(sums.len(), 6);
+        assert_eq!(sums[0], vec![0, 1]);
+        assert_eq!(sums[5], vec![2, 3]);
+
+        let sums = find_all_k_sums(&[3, 1, 3, 5, 1, 3], 2, 6);
+        assert_eq!(sums, vec![vec![0, 2], vec![0, 5], vec![1, 3], vec![2, 5], vec![3, 4]]);
+    }
+
+    #[test]
+    fn counts() {
+        assert_eq!(count_k_sums(&EXAMPLE, 2, 2020), Some(1));
+        assert_eq!(count_k_sums(&EXAMPLE, 3, 2020), Some(1));
+        assert_eq!(count_k_sums(&[1, 1, 1, 1], 2, 2), Some(6));
+        assert_eq!(count_k_sums(&[-3, 5, 2, -1, 0], 2, -1), Some(2));
+        assert_eq!(count_k_sums(&[5, 6], 3, 11), Some(0));
+        assert_eq!(count_k_sums(&EXAMPLE, 4000000000, i64::MAX), Some(0));
+
+        let entries = [4, -2, 7, 7, 1, 0, 3, -2, 5, 9, 2, 6];
+        for k in 1..=5 {
+            for target in -5..=25 {
+                let expected = find_all_k_sums(&entries, k, target).len() as u128;
+                assert_eq!(count_k_sums(&entries, k, target), Some(expected), "k {} target {}", k, target);
+            }
+        }
+    }
+}
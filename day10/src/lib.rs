@@ -1,5 +1,4 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::{parse_numbers, Example, ParseError, Solution};
 
 fn pt1(mut adapters: Vec<i32>) -> i32 {
     adapters.sort();
@@ -41,7 +40,7 @@ fn find_combinations(adapters: &[i32]) -> i64 {
     return result;
 }
 
-fn find_run(adapters: &Vec<i32>, mut cur_idx: usize) -> usize {
+fn find_run(adapters: &[i32], mut cur_idx: usize) -> usize {
     cur_idx += 1;
     while cur_idx < adapters.len() {
         if adapters[cur_idx] - adapters[cur_idx - 1] > 2 {
@@ -62,7 +61,6 @@ fn pt2(mut adapters: Vec<i32>) -> i64 {
 
     while cur_idx < adapters.len() {
         let next_idx = find_run(&adapters, cur_idx);
-        println!("Found run {:?}", &adapters[cur_idx..next_idx]);
         combinations *= find_combinations(&adapters[cur_idx..next_idx]);
         cur_idx = next_idx;
     }
@@ -70,16 +68,41 @@ fn pt2(mut adapters: Vec<i32>) -> i64 {
     return combinations;
 }
 
-fn main() {
-    let file = File::open("input").unwrap();
-    let reader = BufReader::new(file);
-    let adapters: Vec<i32> = reader
-        .lines()
-        .map(|l| l.unwrap().parse::<i32>().unwrap())
-        .collect();
-    let pt1 = pt1(adapters.clone());
-    let pt2 = pt2(adapters);
-    println!("Pt 1: {}, Pt2: {}", pt1, pt2);
+pub struct Adapters {
+    joltages: Vec<i32>,
+}
+
+impl Solution for Adapters {
+    type Part1 = i32;
+    type Part2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example1",
+            input: "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n",
+            options: &[],
+        },
+        Example {
+            name: "example2",
+            input: "\
+28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n\
+8\n17\n7\n9\n4\n2\n34\n10\n3\n",
+            options: &[],
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Adapters, ParseError> {
+        let joltages = parse_numbers(input)?;
+        return Ok(Adapters { joltages: joltages });
+    }
+
+    fn part1(&self) -> i32 {
+        return pt1(self.joltages.clone());
+    }
+
+    fn part2(&self) -> i64 {
+        return pt2(self.joltages.clone());
+    }
 }
 
 #[cfg(test)]
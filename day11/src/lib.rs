@@ -0,0 +1,191 @@
+use common::{Example, ParseError, Solution};
+use grid::{Direction, Grid};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SeatStatus {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl fmt::Display for SeatStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            SeatStatus::Floor => '.',
+            SeatStatus::Empty => 'L',
+            SeatStatus::Occupied => '#',
+        };
+        return write!(f, "{}", c);
+    }
+}
+
+#[derive(Clone)]
+pub struct WaitingArea {
+    seats: Grid<SeatStatus>,
+}
+
+impl WaitingArea {
+    pub fn from_lines(lines: &[String]) -> Result<WaitingArea, ParseError> {
+        let seats = Grid::parse(&lines.join("\n"), |c| match c {
+            'L' => Some(SeatStatus::Empty),
+            '#' => Some(SeatStatus::Occupied),
+            '.' => Some(SeatStatus::Floor),
+            _ => None,
+        })?;
+
+        return Ok(WaitingArea { seats: seats });
+    }
+
+    pub fn occupied_seats(&self) -> usize {
+        return self
+            .seats
+            .iter()
+            .filter(|&(_, &s)| s == SeatStatus::Occupied)
+            .count();
+    }
+
+    fn count_adjacent(&self, x: usize, y: usize, status: SeatStatus) -> usize {
+        return self
+            .seats
+            .neighbours8(x, y)
+            .filter(|&pos| self.seats[pos] == status)
+            .count();
+    }
+
+    /// Works out one round of seating changes, deciding each seat's new
+    /// status from the `count` of occupied seats that it can see. Returns the
+    /// new seating and the number of seats that changed.
+    fn next_round<F: Fn(usize, usize) -> usize>(&self, count: F, tolerance: usize) -> (Grid<SeatStatus>, usize) {
+        let mut new_seats = self.seats.clone();
+        let mut updates = 0;
+
+        for ((x, y), seat) in self.seats.iter() {
+            if *seat == SeatStatus::Empty && count(x, y) == 0 {
+                new_seats[(x, y)] = SeatStatus::Occupied;
+                updates += 1;
+            }
+
+            if *seat == SeatStatus::Occupied && count(x, y) >= tolerance {
+                new_seats[(x, y)] = SeatStatus::Empty;
+                updates += 1;
+            }
+        }
+
+        return (new_seats, updates);
+    }
+
+    pub fn iterate(&mut self) -> usize {
+        let (new_seats, updates) = self.next_round(|x, y| self.count_adjacent(x, y, SeatStatus::Occupied), 4);
+        self.seats = new_seats;
+        return updates;
+    }
+
+    /// The first seat visible from (x, y) looking in `dir`, skipping floor.
+    fn get_status_extended(&self, x: usize, y: usize, dir: Direction) -> SeatStatus {
+        return self
+            .seats
+            .ray(x, y, dir)
+            .map(|pos| self.seats[pos])
+            .find(|&s| s != SeatStatus::Floor)
+            .unwrap_or(SeatStatus::Floor);
+    }
+
+    fn count_adjacent_extended(&self, x: usize, y: usize, status: SeatStatus) -> usize {
+        return Direction::ALL
+            .iter()
+            .filter(|&&dir| self.get_status_extended(x, y, dir) == status)
+            .count();
+    }
+
+    pub fn iterate_extended(&mut self) -> usize {
+        let (new_seats, updates) =
+            self.next_round(|x, y| self.count_adjacent_extended(x, y, SeatStatus::Occupied), 5);
+        self.seats = new_seats;
+        return updates;
+    }
+}
+
+impl fmt::Display for WaitingArea {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.seats);
+    }
+}
+
+fn pt1(mut waiting_area: WaitingArea) -> usize {
+    let mut changes = waiting_area.iterate();
+    while changes > 0 {
+        changes = waiting_area.iterate();
+    }
+
+    return waiting_area.occupied_seats();
+}
+
+fn pt2(mut waiting_area: WaitingArea) -> usize {
+    let mut changes = waiting_area.iterate_extended();
+    while changes > 0 {
+        changes = waiting_area.iterate_extended();
+    }
+
+    return waiting_area.occupied_seats();
+}
+
+impl Solution for WaitingArea {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+",
+        options: &[],
+    }];
+
+    fn parse(input: &str) -> Result<WaitingArea, ParseError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        return WaitingArea::from_lines(&lines);
+    }
+
+    fn part1(&self) -> usize {
+        return pt1(self.clone());
+    }
+
+    fn part2(&self) -> usize {
+        return pt2(self.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pt2_example() {
+        let lines = vec![
+            String::from("L.LL.LL.LL"),
+            String::from("LLLLLLL.LL"),
+            String::from("L.L.L..L.."),
+            String::from("LLLL.LL.LL"),
+            String::from("L.LL.LL.LL"),
+            String::from("L.LLLLL.LL"),
+            String::from("..L.L....."),
+            String::from("LLLLLLLLLL"),
+            String::from("L.LLLLLL.L"),
+            String::from("L.LLLLL.LL"),
+        ];
+
+        let waiting_area = WaitingArea::from_lines(&lines).unwrap();
+        let result = pt2(waiting_area);
+        assert_eq!(result, 26);
+    }
+}
@@ -0,0 +1,329 @@
+mod policy;
+
+pub use policy::{
+    CountPolicy, ForbiddenCharsPolicy, MinDistinctPolicy, PasswordPolicy, PositionPolicy, PositionUnit,
+    RegexPolicy,
+};
+
+use common::{OptionError, Options, ParseError, ParseErrorKind, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct PasswordEntry {
+    /// 1-based line number in the input.
+    pub line: usize,
+    pub min: usize,
+    pub max: usize,
+    pub req_char: String,
+    pub password: String,
+}
+
+impl PasswordEntry {
+    /// The policy from the sled rental place down the street (part 1).
+    pub fn count_policy(&self, unit: PositionUnit) -> CountPolicy {
+        return CountPolicy {
+            min: self.min,
+            max: self.max,
+            req_char: self.req_char.clone(),
+            unit: unit,
+        };
+    }
+
+    /// The Official Toboggan Corporate Policy (part 2).
+    pub fn position_policy(&self, unit: PositionUnit) -> PositionPolicy {
+        return PositionPolicy {
+            first: self.min,
+            second: self.max,
+            req_char: self.req_char.clone(),
+            unit: unit,
+        };
+    }
+}
+
+/// The outcome of checking one password against every policy.
+pub struct LineReport<'a> {
+    pub entry: &'a PasswordEntry,
+    /// Each policy's name, and why the password failed it if it did.
+    pub results: Vec<(&'static str, Result<(), String>)>,
+    /// Each policy's name, and a warning about applying it.
+    pub warnings: Vec<(&'static str, String)>,
+}
+
+/// One policy that one password failed.
+#[derive(Debug, PartialEq)]
+pub struct Failure {
+    pub line: usize,
+    pub policy: &'static str,
+    pub reason: String,
+}
+
+/// A summary of checking a whole password database.
+#[derive(Debug, PartialEq)]
+pub struct Audit {
+    /// Each policy's name with the number of passwords that passed and
+    /// failed it, in the order the policies were applied.
+    pub policies: Vec<(&'static str, usize, usize)>,
+    /// How many lines require each character, most common first.
+    pub required_chars: Vec<(String, usize)>,
+    /// Every failure, by line.
+    pub failures: Vec<Failure>,
+}
+
+impl Audit {
+    pub fn from_reports(reports: &[LineReport]) -> Audit {
+        let mut policies: Vec<(&'static str, usize, usize)> = vec![];
+        let mut required_chars: HashMap<&str, usize> = HashMap::new();
+        let mut failures = vec![];
+
+        for report in reports {
+            *required_chars.entry(&report.entry.req_char).or_insert(0) += 1;
+
+            for (name, result) in &report.results {
+                let idx = match policies.iter().position(|p| p.0 == *name) {
+                    Some(idx) => idx,
+                    None => {
+                        policies.push((name, 0, 0));
+                        policies.len() - 1
+                    }
+                };
+
+                match result {
+                    Ok(()) => policies[idx].1 += 1,
+                    Err(reason) => {
+                        policies[idx].2 += 1;
+                        failures.push(Failure {
+                            line: report.entry.line,
+                            policy: name,
+                            reason: reason.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut required_chars: Vec<(String, usize)> = required_chars
+            .into_iter()
+            .map(|(c, count)| (c.to_string(), count))
+            .collect();
+        required_chars.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        return Audit {
+            policies: policies,
+            required_chars: required_chars,
+            failures: failures,
+        };
+    }
+}
+
+/// Builds the policies that apply to every password, beyond the two given on
+/// each line:
+/// - `forbid`: characters that may not appear
+/// - `min-distinct`: the minimum number of different characters
+/// - `regex`: a regular expression that passwords must match
+pub fn extra_policies(options: &Options) -> Result<Vec<Box<dyn PasswordPolicy>>, OptionError> {
+    let mut policies: Vec<Box<dyn PasswordPolicy>> = vec![];
+    if let Some(chars) = options.get::<String>("forbid")? {
+        policies.push(Box::new(ForbiddenCharsPolicy {
+            chars: chars.chars().collect(),
+        }));
+    }
+    if let Some(min) = options.get("min-distinct")? {
+        policies.push(Box::new(MinDistinctPolicy { min: min }));
+    }
+    if let Some(pattern) = options.get::<String>("regex")? {
+        let re = Regex::new(&pattern).map_err(|e| OptionError {
+            name: String::from("regex"),
+            message: e.to_string(),
+        })?;
+        policies.push(Box::new(RegexPolicy { re: re }));
+    }
+    return Ok(policies);
+}
+
+pub struct PasswordDatabase {
+    entries: Vec<PasswordEntry>,
+    unit: PositionUnit,
+}
+
+impl PasswordDatabase {
+    pub fn entries(&self) -> &[PasswordEntry] {
+        return &self.entries;
+    }
+
+    /// Checks every password against its line's own policies and `extra`.
+    pub fn check(&self, extra: &[Box<dyn PasswordPolicy>]) -> Vec<LineReport<'_>> {
+        return self
+            .entries
+            .iter()
+            .map(|entry| {
+                let own: [Box<dyn PasswordPolicy>; 2] = [
+                    Box::new(entry.count_policy(self.unit)),
+                    Box::new(entry.position_policy(self.unit)),
+                ];
+                let policies: Vec<&Box<dyn PasswordPolicy>> = own.iter().chain(extra.iter()).collect();
+                let results = policies
+                    .iter()
+                    .map(|policy| (policy.name(), policy.check(&entry.password)))
+                    .collect();
+                let warnings = policies
+                    .iter()
+                    .flat_map(|policy| {
+                        let name = policy.name();
+                        return policy.warnings(&entry.password).into_iter().map(move |w| (name, w));
+                    })
+                    .collect();
+
+                return LineReport {
+                    entry: entry,
+                    results: results,
+                    warnings: warnings,
+                };
+            })
+            .collect();
+    }
+}
+
+impl Solution for PasswordDatabase {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<PasswordDatabase, ParseError> {
+        let re = Regex::new(r"^(?P<min>\d+)-(?P<max>\d+) (?P<char>\S+?): (?P<password>\S+)(\s*)?$").unwrap();
+
+        let mut entries = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            let caps = match re.captures(line) {
+                Some(caps) => caps,
+                None => {
+                    let expected = String::from("`<min>-<max> <char>: <password>`");
+                    return Err(ParseError::line(idx + 1, line, ParseErrorKind::Syntax(expected)));
+                }
+            };
+
+            let number = |name: &str| {
+                let text = caps.name(name).unwrap().as_str();
+                return text
+                    .parse::<usize>()
+                    .map_err(|_| ParseError::at(idx + 1, line, text, ParseErrorKind::InvalidNumber));
+            };
+
+            // The required character may be anything that reads as a single
+            // character, including one built from several code points.
+            let req_char = caps.name("char").unwrap().as_str();
+            if req_char.graphemes(true).count() != 1 {
+                let expected = String::from("a single required character");
+                return Err(ParseError::at(idx + 1, line, req_char, ParseErrorKind::Syntax(expected)));
+            }
+
+            entries.push(PasswordEntry {
+                line: idx + 1,
+                min: number("min")?,
+                max: number("max")?,
+                req_char: req_char.to_string(),
+                password: caps["password"].to_string(),
+            });
+        }
+
+        return Ok(PasswordDatabase {
+            entries: entries,
+            unit: PositionUnit::Chars,
+        });
+    }
+
+    /// `positions`: whether passwords are split into `chars` (the default) or
+    /// `graphemes`, both for part 1's counts and part 2's positions.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        if let Some(unit) = options.get("positions")? {
+            self.unit = unit;
+        }
+        return Ok(());
+    }
+
+    fn part1(&self) -> usize {
+        return self
+            .entries
+            .iter()
+            .filter(|e| e.count_policy(self.unit).check(&e.password).is_ok())
+            .count();
+    }
+
+    fn part2(&self) -> usize {
+        return self
+            .entries
+            .iter()
+            .filter(|e| e.position_policy(self.unit).check(&e.password).is_ok())
+            .count();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let db = PasswordDatabase::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+        assert_eq!(db.part1(), 2);
+        assert_eq!(db.part2(), 1);
+    }
+
+    #[test]
+    fn line_reports() {
+        let db = PasswordDatabase::parse("1-3 a: abcde\n1-3 b: cdefg\n").unwrap();
+        let mut options = Options::new();
+        options.set("forbid", "g");
+        let extra = extra_policies(&options).unwrap();
+
+        let reports = db.check(&extra);
+        let names: Vec<&str> = reports[0].results.iter().map(|r| r.0).collect();
+        assert_eq!(names, vec!["count", "position", "forbidden"]);
+        assert!(reports[0].results.iter().all(|r| r.1.is_ok()));
+
+        assert_eq!(reports[1].entry.line, 2);
+        assert!(reports[1].results.iter().all(|r| r.1.is_err()));
+    }
+
+    #[test]
+    fn audit() {
+        let db = PasswordDatabase::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+        let audit = Audit::from_reports(&db.check(&[]));
+        assert_eq!(audit.policies, vec![("count", 2, 1), ("position", 1, 2)]);
+        assert_eq!(
+            audit.required_chars,
+            vec![(String::from("a"), 1), (String::from("b"), 1), (String::from("c"), 1)]
+        );
+
+        let failed: Vec<(usize, &str)> = audit.failures.iter().map(|f| (f.line, f.policy)).collect();
+        assert_eq!(failed, vec![(2, "count"), (2, "position"), (3, "position")]);
+    }
+
+    #[test]
+    fn unicode() {
+        let input = "1-3 é: éaé\n2-4 ü: aüaa\n1-9 a: ab\n";
+        let db = PasswordDatabase::parse(input).unwrap();
+        assert_eq!(db.part1(), 2);
+        assert_eq!(db.part2(), 2);
+
+        let reports = db.check(&[]);
+        assert!(reports[1].warnings.is_empty());
+        assert_eq!(
+            reports[2].warnings,
+            vec![("position", String::from("position 9 is outside the password (2 characters)"))]
+        );
+
+        let err = PasswordDatabase::parse("1-3 ab: abc\n").err().unwrap();
+        assert_eq!(err.offending(), "ab");
+    }
+
+    #[test]
+    fn malformed() {
+        let err = PasswordDatabase::parse("1-3 a: abcde\n1 3 b: cdefg\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.offending(), "1 3 b: cdefg");
+
+        let err = PasswordDatabase::parse("99999999999999999999-3 a: abcde\n").err().unwrap();
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!(err.offending(), "99999999999999999999");
+    }
+}
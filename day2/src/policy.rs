@@ -0,0 +1,330 @@
+use regex::Regex;
+use std::collections::HashSet;
+use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A rule that a password must satisfy. On failure, `check` explains why.
+pub trait PasswordPolicy {
+    /// A short, stable name for the kind of policy, for use in reports.
+    fn name(&self) -> &'static str;
+
+    fn check(&self, password: &str) -> Result<(), String>;
+
+    /// Problems with applying the policy to `password` that don't make it
+    /// fail outright, such as positions past its end.
+    fn warnings(&self, _password: &str) -> Vec<String> {
+        return vec![];
+    }
+}
+
+/// The required character must appear between `min` and `max` times,
+/// counting in `unit`s.
+pub struct CountPolicy {
+    pub min: usize,
+    pub max: usize,
+    pub req_char: String,
+    pub unit: PositionUnit,
+}
+
+impl PasswordPolicy for CountPolicy {
+    fn name(&self) -> &'static str {
+        return "count";
+    }
+
+    fn check(&self, password: &str) -> Result<(), String> {
+        let count = self.unit.split(password).into_iter().filter(|&unit| unit == self.req_char).count();
+        if count >= self.min && count <= self.max {
+            return Ok(());
+        }
+        return Err(format!(
+            "'{}' appears {} times, expected {}-{}",
+            self.req_char, count, self.min, self.max
+        ));
+    }
+
+    fn warnings(&self, _password: &str) -> Vec<String> {
+        return self.unit.mismatch_warning(&self.req_char).into_iter().collect();
+    }
+}
+
+/// What a password's characters are, for counting them and for numbering
+/// positions: Unicode scalar values, or user-perceived characters (extended
+/// grapheme clusters), where for example an `e` followed by a combining acute
+/// accent is a single character.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PositionUnit {
+    Chars,
+    Graphemes,
+}
+
+impl PositionUnit {
+    fn split(self, password: &str) -> Vec<&str> {
+        match self {
+            PositionUnit::Chars => {
+                return password
+                    .char_indices()
+                    .map(|(idx, c)| &password[idx..idx + c.len_utf8()])
+                    .collect();
+            }
+            PositionUnit::Graphemes => return password.graphemes(true).collect(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            PositionUnit::Chars => return "characters",
+            PositionUnit::Graphemes => return "graphemes",
+        }
+    }
+
+    /// A character made of several code points never fits in one char.
+    fn mismatch_warning(self, req_char: &str) -> Option<String> {
+        let code_points = req_char.chars().count();
+        if self == PositionUnit::Chars && code_points > 1 {
+            return Some(format!(
+                "'{}' is {} code points, so no single character can match it; use graphemes instead",
+                req_char, code_points
+            ));
+        }
+        return None;
+    }
+}
+
+impl FromStr for PositionUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<PositionUnit, ()> {
+        match s {
+            "chars" => return Ok(PositionUnit::Chars),
+            "graphemes" => return Ok(PositionUnit::Graphemes),
+            _ => return Err(()),
+        }
+    }
+}
+
+/// Exactly one of the (1-based) positions `first` and `second` must hold the
+/// required character. A position outside the password never holds it.
+pub struct PositionPolicy {
+    pub first: usize,
+    pub second: usize,
+    pub req_char: String,
+    pub unit: PositionUnit,
+}
+
+impl PositionPolicy {
+    fn matches(&self, units: &[&str], position: usize) -> bool {
+        if position == 0 {
+            return false;
+        }
+        return units.get(position - 1) == Some(&self.req_char.as_str());
+    }
+}
+
+impl PasswordPolicy for PositionPolicy {
+    fn name(&self) -> &'static str {
+        return "position";
+    }
+
+    fn check(&self, password: &str) -> Result<(), String> {
+        let units = self.unit.split(password);
+        let first_match = self.matches(&units, self.first);
+        let second_match = self.matches(&units, self.second);
+        if first_match != second_match {
+            return Ok(());
+        }
+        return Err(format!(
+            "'{}' is at {} of positions {} and {}, expected exactly one",
+            self.req_char,
+            if first_match { "both" } else { "neither" },
+            self.first,
+            self.second
+        ));
+    }
+
+    fn warnings(&self, password: &str) -> Vec<String> {
+        let len = self.unit.split(password).len();
+        let mut positions = vec![self.first];
+        if self.second != self.first {
+            positions.push(self.second);
+        }
+
+        let mut warnings: Vec<String> = positions
+            .into_iter()
+            .filter(|&position| position == 0 || position > len)
+            .map(|position| {
+                return format!(
+                    "position {} is outside the password ({} {})",
+                    position,
+                    len,
+                    self.unit.name()
+                );
+            })
+            .collect();
+
+        warnings.extend(self.unit.mismatch_warning(&self.req_char));
+        return warnings;
+    }
+}
+
+/// None of the given characters may appear.
+pub struct ForbiddenCharsPolicy {
+    pub chars: Vec<char>,
+}
+
+impl PasswordPolicy for ForbiddenCharsPolicy {
+    fn name(&self) -> &'static str {
+        return "forbidden";
+    }
+
+    fn check(&self, password: &str) -> Result<(), String> {
+        match password.chars().find(|c| self.chars.contains(c)) {
+            Some(c) => return Err(format!("contains forbidden character '{}'", c)),
+            None => return Ok(()),
+        }
+    }
+}
+
+/// The password must use at least `min` different characters.
+pub struct MinDistinctPolicy {
+    pub min: usize,
+}
+
+impl PasswordPolicy for MinDistinctPolicy {
+    fn name(&self) -> &'static str {
+        return "distinct";
+    }
+
+    fn check(&self, password: &str) -> Result<(), String> {
+        let distinct = password.chars().collect::<HashSet<char>>().len();
+        if distinct >= self.min {
+            return Ok(());
+        }
+        return Err(format!("{} distinct characters, expected at least {}", distinct, self.min));
+    }
+}
+
+/// The password must match a regular expression.
+pub struct RegexPolicy {
+    pub re: Regex,
+}
+
+impl PasswordPolicy for RegexPolicy {
+    fn name(&self) -> &'static str {
+        return "regex";
+    }
+
+    fn check(&self, password: &str) -> Result<(), String> {
+        if self.re.is_match(password) {
+            return Ok(());
+        }
+        return Err(format!("doesn't match /{}/", self.re.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_and_position() {
+        let count = CountPolicy {
+            min: 1,
+            max: 3,
+            req_char: String::from("b"),
+            unit: PositionUnit::Chars,
+        };
+        assert_eq!(count.check("abcde"), Ok(()));
+        assert_eq!(count.check("cdefg"), Err(String::from("'b' appears 0 times, expected 1-3")));
+
+        let position = PositionPolicy {
+            first: 2,
+            second: 9,
+            req_char: String::from("c"),
+            unit: PositionUnit::Chars,
+        };
+        assert!(position.check("ccccccccd").is_ok());
+        assert_eq!(
+            position.check("ccccccccc"),
+            Err(String::from("'c' is at both of positions 2 and 9, expected exactly one"))
+        );
+    }
+
+    #[test]
+    fn unicode_positions() {
+        let mut position = PositionPolicy {
+            first: 1,
+            second: 3,
+            req_char: String::from("é"),
+            unit: PositionUnit::Chars,
+        };
+        assert!(position.check("ééé").is_err());
+        assert!(position.check("aéé").is_ok());
+        assert!(position.check("e\u{301}ab").is_err());
+
+        position.req_char = String::from("e\u{301}");
+        assert!(position.check("e\u{301}ab").is_err());
+        assert_eq!(
+            position.warnings("e\u{301}ab"),
+            vec![String::from(
+                "'e\u{301}' is 2 code points, so no single character can match it; use graphemes instead"
+            )]
+        );
+
+        position.unit = PositionUnit::Graphemes;
+        assert!(position.check("e\u{301}ab").is_ok());
+        assert!(position.warnings("e\u{301}ab").is_empty());
+    }
+
+    #[test]
+    fn unicode_counts() {
+        let mut count = CountPolicy {
+            min: 1,
+            max: 1,
+            req_char: String::from("e"),
+            unit: PositionUnit::Chars,
+        };
+        assert!(count.check("e\u{301}x").is_ok());
+
+        // The position policy finds no `e` here, and neither should the count.
+        count.unit = PositionUnit::Graphemes;
+        assert_eq!(count.check("e\u{301}x"), Err(String::from("'e' appears 0 times, expected 1-1")));
+
+        count.req_char = String::from("e\u{301}");
+        assert!(count.check("e\u{301}x").is_ok());
+        count.unit = PositionUnit::Chars;
+        assert!(count.check("e\u{301}x").is_err());
+        assert_eq!(count.warnings("e\u{301}x").len(), 1);
+    }
+
+    #[test]
+    fn out_of_range_positions() {
+        let position = PositionPolicy {
+            first: 2,
+            second: 9,
+            req_char: String::from("a"),
+            unit: PositionUnit::Chars,
+        };
+        assert!(position.check("aa").is_ok());
+        assert!(position.check("ab").is_err());
+        assert_eq!(
+            position.warnings("ab"),
+            vec![String::from("position 9 is outside the password (2 characters)")]
+        );
+        assert!(position.warnings("abcdefghi").is_empty());
+    }
+
+    #[test]
+    fn extra_policies() {
+        let forbidden = ForbiddenCharsPolicy { chars: vec!['x', 'y'] };
+        assert!(forbidden.check("abc").is_ok());
+        assert!(forbidden.check("abyc").is_err());
+
+        let distinct = MinDistinctPolicy { min: 3 };
+        assert!(distinct.check("abca").is_ok());
+        assert!(distinct.check("abab").is_err());
+
+        let regex = RegexPolicy { re: Regex::new("^[a-z]+[0-9]$").unwrap() };
+        assert!(regex.check("abc1").is_ok());
+        assert!(regex.check("abc").is_err());
+    }
+}
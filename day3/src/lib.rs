@@ -0,0 +1,390 @@
+mod render;
+mod slope;
+mod terrain;
+
+pub use render::{to_ppm, to_svg, PathSquare};
+pub use slope::Slope;
+pub use terrain::{Legend, Terrain};
+
+use common::{Example, ParseError, ParseErrorKind, Solution};
+use grid::Grid;
+use std::fmt;
+
+/// The slopes that part 2 checks.
+pub const PUZZLE_SLOPES: [Slope; 5] = [
+    Slope { right: 1, down: 1 },
+    Slope { right: 3, down: 1 },
+    Slope { right: 5, down: 1 },
+    Slope { right: 7, down: 1 },
+    Slope { right: 1, down: 2 },
+];
+
+/// Whether a slope search looks for the fewest or the most trees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Objective {
+    Fewest,
+    Most,
+}
+
+/// The best tree count found by a slope search, and every slope that
+/// achieves it, ordered by `down` and then `right`.
+#[derive(Debug, PartialEq)]
+pub struct SlopeSearch {
+    pub trees: u64,
+    pub slopes: Vec<Slope>,
+}
+
+/// The squares landed on along a slope: for each terrain in the legend, how
+/// many squares of it were landed on and what they cost in total. A cost is
+/// `None` if it doesn't fit in a `u64`.
+#[derive(Debug, PartialEq)]
+pub struct Traversal<'a> {
+    pub terrains: Vec<(&'a Terrain, u64, Option<u64>)>,
+    pub total_cost: Option<u64>,
+}
+
+/// The product of the trees hit on each slope, or that it's too big.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Product {
+    Found(u64),
+    Overflow,
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Product::Found(product) => return write!(f, "{}", product),
+            Product::Overflow => return write!(f, "product overflows a 64-bit integer"),
+        }
+    }
+}
+
+/// A square of the map, as an index into the legend's terrains.
+#[derive(Clone, Copy, PartialEq)]
+struct MapSquare(usize);
+
+pub struct Map {
+    grid: Grid<MapSquare>,
+    legend: Legend,
+    tree: Option<MapSquare>,
+}
+
+impl Map {
+    /// Parses a map drawn with the puzzle's own legend.
+    pub fn from_strings(input: &[String]) -> Result<Map, ParseError> {
+        return Map::from_strings_with_legend(input, Legend::default());
+    }
+
+    /// Parses a map drawn with `legend`, rejecting any character that isn't
+    /// in it. Trees are the squares of the terrain called `tree`.
+    pub fn from_strings_with_legend(input: &[String], legend: Legend) -> Result<Map, ParseError> {
+        let text = input.join("\n");
+        let grid = Grid::parse(&text, |c| legend.terrain(c).map(MapSquare)).map_err(|mut err| {
+            if let ParseErrorKind::Unrecognized(_) = err.kind {
+                err.kind = ParseErrorKind::Unrecognized(String::from("terrain"));
+            }
+            return err;
+        })?;
+
+        let tree = legend.find("tree").map(MapSquare);
+        return Ok(Map {
+            grid: grid,
+            legend: legend,
+            tree: tree,
+        });
+    }
+
+    pub fn legend(&self) -> &Legend {
+        return &self.legend;
+    }
+
+    /// Totals the terrain landed on, and its cost, going from the top left to
+    /// the bottom of the map.
+    pub fn traverse(&self, slope: Slope) -> Traversal<'_> {
+        let width = self.grid.width() as isize;
+        let right = slope.right.rem_euclid(width);
+        let mut squares = vec![0u64; self.legend.terrains().len()];
+        let mut x: isize = 0;
+        for y in (slope.down..self.grid.height()).step_by(slope.down) {
+            x = (x + right) % width;
+            squares[self.grid.get_wrapped(x, y as isize).0] += 1;
+        }
+
+        let terrains: Vec<(&Terrain, u64, Option<u64>)> = self
+            .legend
+            .terrains()
+            .iter()
+            .zip(squares)
+            .map(|(terrain, count)| (terrain, count, count.checked_mul(terrain.cost)))
+            .collect();
+        let total_cost = terrains
+            .iter()
+            .try_fold(0u64, |total, &(_, _, cost)| total.checked_add(cost?));
+        return Traversal {
+            terrains: terrains,
+            total_cost: total_cost,
+        };
+    }
+
+    /// Counts the trees hit going from the top left to the bottom of the map.
+    pub fn count_trees(&self, slope: Slope) -> u64 {
+        // The map repeats to the left and right as far as needed, so only
+        // the column within one copy of it matters.
+        let width = self.grid.width() as isize;
+        let right = slope.right.rem_euclid(width);
+        let mut x: isize = 0;
+        let mut count = 0;
+        for y in (slope.down..self.grid.height()).step_by(slope.down) {
+            x = (x + right) % width;
+            if Some(*self.grid.get_wrapped(x, y as isize)) == self.tree {
+                count += 1;
+            }
+        }
+
+        return count;
+    }
+
+    /// Draws the path taken on `slope` over the map, repeating the map to the
+    /// left and right as far as the path goes. Squares landed on are marked
+    /// as visited or hit; the starting square is not. Terrain other than trees
+    /// is drawn as open.
+    ///
+    /// A slope a map width or more across is drawn as the one less than a
+    /// width across, in the same direction, that lands on the same squares.
+    pub fn path_overlay(&self, slope: Slope) -> Grid<PathSquare> {
+        let width = self.grid.width() as isize;
+        let height = self.grid.height();
+        let right = slope.right % width;
+        let steps = ((height - 1) / slope.down) as isize;
+        let last = steps * right;
+
+        // The range of whole map repeats between the start and the end.
+        let first_repeat = last.min(0).div_euclid(width);
+        let last_repeat = last.max(0).div_euclid(width);
+        let repeats = (last_repeat - first_repeat + 1) as usize;
+        let offset = first_repeat * width;
+
+        let mut overlay = Grid::new(self.grid.width() * repeats, height, PathSquare::Open);
+        for y in 0..overlay.height() {
+            for x in 0..overlay.width() {
+                if Some(*self.grid.get_wrapped(x as isize + offset, y as isize)) == self.tree {
+                    overlay[(x, y)] = PathSquare::Tree;
+                }
+            }
+        }
+
+        for step in 1..=steps {
+            let pos = ((step * right - offset) as usize, step as usize * slope.down);
+            overlay[pos] = match overlay[pos] {
+                PathSquare::Tree => PathSquare::Hit,
+                _ => PathSquare::Visited,
+            };
+        }
+
+        return overlay;
+    }
+
+    /// Searches every slope with `right` in 1..=max_right and `down` in
+    /// 1..=max_down for the fewest or most trees hit.
+    ///
+    /// Since the map repeats horizontally, moving `right` squares lands on
+    /// the same columns as moving `right % width`, so each down step only
+    /// needs counting for `width` different right steps, however large
+    /// `max_right` is.
+    pub fn optimal_slopes(
+        &self,
+        max_right: usize,
+        max_down: usize,
+        objective: Objective,
+    ) -> SlopeSearch {
+        let width = self.grid.width();
+        let mut best = SlopeSearch {
+            trees: 0,
+            slopes: vec![],
+        };
+
+        for down in 1..=max_down {
+            let counts: Vec<u64> = (0..width.min(max_right + 1))
+                .map(|right| self.count_trees(Slope::new(right as isize, down)))
+                .collect();
+
+            for right in 1..=max_right {
+                let trees = counts[right % width];
+                let better = match objective {
+                    Objective::Fewest => trees < best.trees,
+                    Objective::Most => trees > best.trees,
+                };
+
+                if best.slopes.is_empty() || better {
+                    best.trees = trees;
+                    best.slopes.clear();
+                }
+                if trees == best.trees {
+                    best.slopes.push(Slope::new(right as isize, down));
+                }
+            }
+        }
+
+        return best;
+    }
+
+    pub fn width(&self) -> usize {
+        return self.grid.width();
+    }
+
+    pub fn height(&self) -> usize {
+        return self.grid.height();
+    }
+
+    /// The product of the trees hit on each slope, or `None` if it overflows.
+    pub fn product_of_trees(&self, slopes: &[Slope]) -> Option<u64> {
+        return slopes
+            .iter()
+            .try_fold(1u64, |acc, &slope| acc.checked_mul(self.count_trees(slope)));
+    }
+}
+
+impl Solution for Map {
+    type Part1 = u64;
+    type Part2 = Product;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+",
+        options: &[],
+    }];
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        return Map::from_strings(&lines);
+    }
+
+    fn part1(&self) -> u64 {
+        return self.count_trees(Slope::new(3, 1));
+    }
+
+    fn part2(&self) -> Product {
+        match self.product_of_trees(&PUZZLE_SLOPES) {
+            Some(product) => return Product::Found(product),
+            None => return Product::Overflow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Map {
+        return Map::parse(Map::EXAMPLES[0].input).unwrap();
+    }
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(example().part1(), 7);
+    }
+
+    #[test]
+    fn pt2_example() {
+        let map = example();
+        let counts: Vec<u64> = PUZZLE_SLOPES.iter().map(|&s| map.count_trees(s)).collect();
+        assert_eq!(counts, vec![2, 7, 3, 4, 2]);
+        assert_eq!(map.part2(), Product::Found(336));
+
+        // Every slope hits a tree on each of 100001 rows.
+        let tall = Map::parse(&"#\n".repeat(100002)).unwrap();
+        assert_eq!(tall.part2(), Product::Overflow);
+        assert_eq!(tall.part2().to_string(), "product overflows a 64-bit integer");
+    }
+
+    #[test]
+    fn optimal_slopes() {
+        let map = example();
+        let most = map.optimal_slopes(map.width(), map.height(), Objective::Most);
+        assert_eq!(most.trees, 7);
+        assert_eq!(most.slopes, vec![Slope::new(3, 1)]);
+
+        let fewest = map.optimal_slopes(map.width(), 2, Objective::Fewest);
+        assert_eq!(fewest.trees, 0);
+        assert_eq!(fewest.slopes, vec![Slope::new(5, 2)]);
+
+        // Moving a whole map width further right changes nothing.
+        let wider = map.optimal_slopes(2 * map.width() + 3, 1, Objective::Most);
+        assert_eq!(
+            wider.slopes,
+            vec![Slope::new(3, 1), Slope::new(14, 1), Slope::new(25, 1)]
+        );
+    }
+
+    #[test]
+    fn path_overlay() {
+        let overlay = example().path_overlay(Slope::new(3, 1));
+        assert_eq!(overlay.width(), 33);
+        assert_eq!(overlay.iter().filter(|(_, &s)| s == PathSquare::Hit).count(), 7);
+        assert_eq!(overlay.iter().filter(|(_, &s)| s == PathSquare::Visited).count(), 3);
+
+        let rows: Vec<String> = overlay.to_string().lines().map(String::from).collect();
+        assert!(rows[1].starts_with("#..O#"));
+        assert!(rows[10].ends_with("..#...X.#"));
+
+        let left = example().path_overlay(Slope::new(-1, 2));
+        assert_eq!(left.width(), 22);
+        assert_eq!(left.to_string().lines().nth(2).unwrap(), ".#....#..#O.#....#..#.");
+    }
+
+    #[test]
+    fn terrain_costs() {
+        let lines: Vec<String> = vec![String::from("..~#"), String::from(".#~."), String::from("~~~#")];
+        let legend: Legend = ".=open:0 #=tree:1 ~=snow:3".parse().unwrap();
+        let map = Map::from_strings_with_legend(&lines, legend).unwrap();
+        assert_eq!(map.count_trees(Slope::new(1, 1)), 1);
+
+        let traversal = map.traverse(Slope::new(1, 1));
+        let totals: Vec<(&str, u64, Option<u64>)> =
+            traversal.terrains.iter().map(|&(t, n, cost)| (t.name.as_str(), n, cost)).collect();
+        assert_eq!(totals, vec![("open", 0, Some(0)), ("tree", 1, Some(1)), ("snow", 1, Some(3))]);
+        assert_eq!(traversal.total_cost, Some(4));
+
+        // Costs too big to add up, or to multiply by the squares landed on.
+        let legend: Legend = ".=open:0 #=tree:1 ~=snow:18446744073709551615".parse().unwrap();
+        let map = Map::from_strings_with_legend(&lines, legend).unwrap();
+        let traversal = map.traverse(Slope::new(1, 1));
+        assert_eq!(traversal.terrains[2].2, Some(u64::MAX));
+        assert_eq!(traversal.total_cost, None);
+        let traversal = map.traverse(Slope::new(2, 1));
+        assert_eq!(traversal.terrains[2].2, None);
+        assert_eq!(traversal.total_cost, None);
+
+        let err = Map::from_strings(&lines).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(err.kind, ParseErrorKind::Unrecognized(String::from("terrain")));
+    }
+
+    #[test]
+    fn other_slopes() {
+        let map = Map::parse("....\n...#\n..#.\n.#..\n").unwrap();
+        assert_eq!(map.count_trees(Slope::new(-1, 1)), 3);
+        assert_eq!(map.count_trees(Slope::new(0, 1)), 0);
+        assert_eq!(map.count_trees(Slope::per_row(1, 3)), 1);
+        assert_eq!(map.product_of_trees(&[Slope::new(-1, 1); 2]), Some(9));
+
+        // Any step across is fine, however far.
+        assert_eq!(map.count_trees(Slope::new(isize::MAX, 1)), map.count_trees(Slope::new(3, 1)));
+        assert_eq!(map.count_trees(Slope::new(isize::MIN, 1)), map.count_trees(Slope::new(0, 1)));
+        assert_eq!(map.traverse(Slope::new(isize::MAX, 1)), map.traverse(Slope::new(3, 1)));
+        assert_eq!(map.path_overlay(Slope::new(isize::MAX, 1)), map.path_overlay(Slope::new(3, 1)));
+        assert_eq!(map.path_overlay(Slope::new(-5, 1)), map.path_overlay(Slope::new(-1, 1)));
+    }
+}
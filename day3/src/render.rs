@@ -0,0 +1,87 @@
+use grid::Grid;
+use std::fmt;
+
+/// A square of a map with a toboggan's path drawn over it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathSquare {
+    Open,
+    Tree,
+    Visited,
+    Hit,
+}
+
+impl PathSquare {
+    fn colour(self) -> [u8; 3] {
+        match self {
+            PathSquare::Open => return [255, 255, 255],
+            PathSquare::Tree => return [34, 120, 50],
+            PathSquare::Visited => return [80, 140, 230],
+            PathSquare::Hit => return [220, 40, 40],
+        }
+    }
+}
+
+impl fmt::Display for PathSquare {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            PathSquare::Open => '.',
+            PathSquare::Tree => '#',
+            PathSquare::Visited => 'O',
+            PathSquare::Hit => 'X',
+        };
+        return write!(f, "{}", c);
+    }
+}
+
+/// Encodes a path overlay as a binary PPM image, drawing each square as a
+/// `scale` by `scale` block of pixels.
+pub fn to_ppm(overlay: &Grid<PathSquare>, scale: usize) -> Vec<u8> {
+    let width = overlay.width() * scale;
+    let height = overlay.height() * scale;
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.reserve(width * height * 3);
+
+    for row in overlay.rows() {
+        for _ in 0..scale {
+            for square in row {
+                for _ in 0..scale {
+                    image.extend_from_slice(&square.colour());
+                }
+            }
+        }
+    }
+    return image;
+}
+
+/// Encodes a path overlay as an SVG image with `scale` pixel squares. Open
+/// squares are left as the background.
+pub fn to_svg(overlay: &Grid<PathSquare>, scale: usize) -> String {
+    let [r, g, b] = PathSquare::Open.colour();
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" shape-rendering=\"crispEdges\">\n\
+         <rect width=\"{w}\" height=\"{h}\" fill=\"rgb({r},{g},{b})\"/>\n",
+        w = overlay.width() * scale,
+        h = overlay.height() * scale,
+        r = r,
+        g = g,
+        b = b
+    );
+
+    for ((x, y), square) in overlay.iter() {
+        if *square == PathSquare::Open {
+            continue;
+        }
+        let [r, g, b] = square.colour();
+        svg.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" fill=\"rgb({},{},{})\"/>\n",
+            x * scale,
+            y * scale,
+            r,
+            g,
+            b,
+            s = scale
+        ));
+    }
+    svg.push_str("</svg>\n");
+    return svg;
+}
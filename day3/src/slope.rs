@@ -0,0 +1,85 @@
+use std::fmt;
+use std::str::FromStr;
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    return gcd(b, a % b);
+}
+
+/// How far a toboggan moves on each step: `right` squares to the right
+/// (negative for left) and `down` rows down.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub fn new(right: isize, down: usize) -> Slope {
+        assert!(down > 0, "a slope must go down");
+        return Slope {
+            right: right,
+            down: down,
+        };
+    }
+
+    /// A slope moving `numerator / denominator` squares right per row, which
+    /// only lands on rows where that comes out to a whole square. For
+    /// example 3/2 per row is the same as 3 right and 2 down.
+    pub fn per_row(numerator: isize, denominator: usize) -> Slope {
+        let divisor = gcd(numerator.unsigned_abs(), denominator);
+        return Slope::new(numerator / divisor as isize, denominator / divisor);
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "({}, {})", self.right, self.down);
+    }
+}
+
+/// Either `<right>,<down>` or a per-row ratio `<numerator>/<denominator>`.
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Slope, String> {
+        let invalid = || format!("invalid slope '{}', expected <right>,<down> or <n>/<d>", s);
+
+        let (right, down, per_row) = if let Some((right, down)) = s.split_once(',') {
+            (right, down, false)
+        } else if let Some((numerator, denominator)) = s.split_once('/') {
+            (numerator, denominator, true)
+        } else {
+            return Err(invalid());
+        };
+
+        let right = right.trim().parse::<isize>().map_err(|_| invalid())?;
+        let down = down.trim().parse::<usize>().map_err(|_| invalid())?;
+        if down == 0 {
+            return Err(format!("invalid slope '{}', it must go down", s));
+        }
+
+        if per_row {
+            return Ok(Slope::per_row(right, down));
+        }
+        return Ok(Slope::new(right, down));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!("3,1".parse(), Ok(Slope::new(3, 1)));
+        assert_eq!("-1, 2".parse(), Ok(Slope::new(-1, 2)));
+        assert_eq!("1/2".parse(), Ok(Slope::new(1, 2)));
+        assert_eq!("6/4".parse(), Ok(Slope::new(3, 2)));
+        assert_eq!("-4/2".parse(), Ok(Slope::new(-2, 1)));
+        assert!("3,0".parse::<Slope>().is_err());
+        assert!("3".parse::<Slope>().is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::str::FromStr;
+
+/// A kind of terrain, and what it costs to land on it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Terrain {
+    pub name: String,
+    pub cost: u64,
+}
+
+/// Which terrain each map character stands for. Several characters may
+/// stand for the same terrain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Legend {
+    terrains: Vec<Terrain>,
+    symbols: Vec<(char, usize)>,
+}
+
+impl Legend {
+    pub fn new() -> Legend {
+        return Legend {
+            terrains: vec![],
+            symbols: vec![],
+        };
+    }
+
+    /// Adds `symbol` as a square of terrain `name`, creating the terrain if
+    /// it is new.
+    pub fn add(&mut self, symbol: char, name: &str, cost: u64) -> Result<(), String> {
+        if self.terrain(symbol).is_some() {
+            return Err(format!("'{}' is already in the legend", symbol));
+        }
+
+        let index = match self.find(name) {
+            Some(index) if self.terrains[index].cost != cost => {
+                return Err(format!("terrain '{}' is given two different costs", name));
+            }
+            Some(index) => index,
+            None => {
+                self.terrains.push(Terrain {
+                    name: name.to_string(),
+                    cost: cost,
+                });
+                self.terrains.len() - 1
+            }
+        };
+        self.symbols.push((symbol, index));
+        return Ok(());
+    }
+
+    /// The index of the terrain that `symbol` stands for.
+    pub fn terrain(&self, symbol: char) -> Option<usize> {
+        return self.symbols.iter().find(|&&(s, _)| s == symbol).map(|&(_, index)| index);
+    }
+
+    /// The index of the terrain called `name`.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        return self.terrains.iter().position(|t| t.name == name);
+    }
+
+    pub fn terrains(&self) -> &[Terrain] {
+        return &self.terrains;
+    }
+}
+
+/// The puzzle's own legend: open squares cost nothing, trees cost one.
+impl Default for Legend {
+    fn default() -> Legend {
+        let mut legend = Legend::new();
+        legend.add('.', "open", 0).unwrap();
+        legend.add('#', "tree", 1).unwrap();
+        return legend;
+    }
+}
+
+/// Parses a whitespace-separated list of `<char>=<name>:<cost>` entries,
+/// such as `.=open:0 #=tree:1 ~=snow:2`.
+impl FromStr for Legend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Legend, String> {
+        let mut legend = Legend::new();
+        for entry in s.split_whitespace() {
+            let mut chars = entry.chars();
+            let symbol = chars.next().unwrap();
+            let (name, cost) = chars
+                .as_str()
+                .strip_prefix('=')
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| format!("expected <char>=<name>:<cost>, got '{}'", entry))?;
+            let cost = cost
+                .parse::<u64>()
+                .map_err(|_| format!("invalid cost '{}' for '{}'", cost, symbol))?;
+            legend.add(symbol, name, cost)?;
+        }
+
+        if legend.terrains.is_empty() {
+            return Err(String::from("the legend is empty"));
+        }
+        return Ok(legend);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let legend: Legend = "==water:3 .=open:0 ,=open:0 #=tree:1".parse().unwrap();
+        assert_eq!(legend.terrains().len(), 3);
+        assert_eq!(legend.terrain('='), legend.find("water"));
+        assert_eq!(legend.terrain(','), legend.terrain('.'));
+        assert_eq!(legend.terrain('~'), None);
+
+        assert!("#=tree".parse::<Legend>().is_err());
+        assert!("#=tree:1 #=rock:2".parse::<Legend>().is_err());
+        assert!(".=open:0 ,=open:1".parse::<Legend>().is_err());
+    }
+}
@@ -0,0 +1,577 @@
+mod passport;
+mod schema;
+mod validator;
+
+pub use passport::{
+    BirthYear, CountryId, ExpirationYear, EyeColour, HairColour, Height, HeightUnit, IssueYear, Passport, PassportId,
+    Rgb,
+};
+pub use schema::{FieldRule, FieldType, Schema, Violation};
+pub use validator::{Validator, Verdict};
+
+use common::{line_groups, Example, LineGroup, OptionError, Options, ParseError, ParseErrorKind, Solution};
+use regex::{CaptureLocations, Regex};
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Matches one `key:value` token of a passport record.
+const TOKEN_PATTERN: &str = r"(?P<key>\S+):(?P<value>\S+)(\s+|$)";
+
+/// A passport as written in the batch, before any validation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub fields: HashMap<String, String>,
+    /// The 1-based lines the record starts and ends on.
+    pub first_line: usize,
+    pub last_line: usize,
+}
+
+impl Record {
+    /// The fields in canonical order: the schema's fields in schema order,
+    /// then any others alphabetically. `strip_invalid` drops the fields that
+    /// break the schema's rules, and the others too, since strict parsing
+    /// rejects fields the schema doesn't know.
+    pub fn canonical_fields(&self, schema: &Schema, strip_invalid: bool) -> Vec<(&str, &str)> {
+        let mut fields: Vec<(&str, &str)> = vec![];
+        for rule in &schema.fields {
+            if let Some((key, value)) = self.fields.get_key_value(&rule.name) {
+                if !strip_invalid || rule.is_valid(value) {
+                    fields.push((key, value));
+                }
+            }
+        }
+        if strip_invalid {
+            return fields;
+        }
+
+        let mut others: Vec<(&str, &str)> = self
+            .fields
+            .iter()
+            .filter(|(key, _)| schema.field(key).is_none())
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        others.sort_unstable();
+        fields.extend(others);
+        return fields;
+    }
+}
+
+/// How `PassportBatch::normalise` lays out each passport.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Layout {
+    /// All of a passport's fields on one line.
+    Line,
+    /// Each field on a line of its own.
+    Lines,
+}
+
+impl FromStr for Layout {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Layout, ()> {
+        match s {
+            "line" => return Ok(Layout::Line),
+            "lines" => return Ok(Layout::Lines),
+            _ => return Err(()),
+        }
+    }
+}
+
+/// A passport that failed validation, and every reason why.
+#[derive(Debug, PartialEq)]
+pub struct Rejection<'a> {
+    /// The 0-based position of the record in the batch.
+    pub index: usize,
+    pub record: &'a Record,
+    pub violations: Vec<Violation>,
+}
+
+/// How forgiving to be of oddities in a passport batch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParseMode {
+    /// Skips anything that isn't a `key:value` pair, and lets a repeated key
+    /// overwrite the earlier value.
+    Lenient,
+    /// Reports duplicate keys, keys that aren't in the schema and tokens that
+    /// aren't `key:value` pairs.
+    Strict,
+}
+
+/// Checks one whitespace-separated token of a strictly parsed record, adding
+/// it to the record if it's good.
+fn read_strict_token(
+    record: &mut Record,
+    (line_number, line): (usize, &str),
+    token: &str,
+    schema: &Schema,
+) -> Result<(), ParseError> {
+    let (key, value) = match token.split_once(':') {
+        Some((key, value)) if !key.is_empty() && !value.is_empty() && !value.contains(':') => (key, value),
+        _ => {
+            let kind = ParseErrorKind::Syntax(String::from("a key:value pair"));
+            return Err(ParseError::at(line_number, line, token, kind));
+        }
+    };
+
+    if schema.field(key).is_none() {
+        let kind = ParseErrorKind::Unrecognized(String::from("passport field"));
+        return Err(ParseError::at(line_number, line, &token[..key.len()], kind));
+    }
+    if record.fields.contains_key(key) {
+        let kind = ParseErrorKind::Syntax(format!("only one {} field per passport", key));
+        return Err(ParseError::at(line_number, line, token, kind));
+    }
+
+    record.fields.insert(key.to_string(), value.to_string());
+    return Ok(());
+}
+
+/// Calls `f` with the key and value of each `key:value` token in `line`,
+/// skipping anything else, as lenient parsing does. `re` is `TOKEN_PATTERN`
+/// compiled, and `locations` its capture locations, which are reused so that
+/// nothing is allocated.
+fn lenient_tokens<F: FnMut(&str, &str)>(re: &Regex, locations: &mut CaptureLocations, line: &str, mut f: F) {
+    let mut start = 0;
+    while let Some(found) = re.captures_read_at(locations, line, start) {
+        let (key_start, key_end) = locations.get(1).unwrap();
+        let (value_start, value_end) = locations.get(2).unwrap();
+        f(&line[key_start..key_end], &line[value_start..value_end]);
+        start = found.end();
+    }
+}
+
+/// A record with no fields yet, spanning `group`.
+fn empty_record(group: &LineGroup) -> Record {
+    return Record {
+        fields: HashMap::new(),
+        first_line: group.first_line,
+        last_line: group.last_line(),
+    };
+}
+
+/// Reads one record from a group of lines. Problems found in strict mode are
+/// added to `errors`.
+fn read_record(
+    group: &LineGroup,
+    mode: ParseMode,
+    schema: &Schema,
+    re: &Regex,
+    errors: &mut Vec<ParseError>,
+) -> Record {
+    let mut record = empty_record(group);
+    let mut locations = re.capture_locations();
+    for (idx, line) in group.lines.iter().enumerate() {
+        match mode {
+            ParseMode::Lenient => {
+                lenient_tokens(re, &mut locations, line, |key, value| {
+                    record.fields.insert(key.to_string(), value.to_string());
+                });
+            }
+            ParseMode::Strict => {
+                let line_number = group.first_line + idx;
+                for token in line.split_whitespace() {
+                    if let Err(err) = read_strict_token(&mut record, (line_number, line), token, schema) {
+                        errors.push(err);
+                    }
+                }
+            }
+        }
+    }
+
+    return record;
+}
+
+/// Reads records one at a time, leniently, so that batches of any size can be
+/// checked without holding them in memory.
+pub fn read_records<R: BufRead>(reader: R) -> impl Iterator<Item = io::Result<Record>> {
+    let re = Regex::new(TOKEN_PATTERN).unwrap();
+    let mut locations = re.capture_locations();
+    return line_groups(reader).map(move |group| {
+        let group = group?;
+        let mut record = empty_record(&group);
+        for line in &group.lines {
+            lenient_tokens(&re, &mut locations, line, |key, value| {
+                record.fields.insert(key.to_string(), value.to_string());
+            });
+        }
+        return Ok(record);
+    });
+}
+
+/// Reads blank-line separated records. In strict mode, every problem found is
+/// returned rather than the records.
+fn read_passports(input: &str, mode: ParseMode, schema: &Schema) -> Result<Vec<Record>, Vec<ParseError>> {
+    let re = Regex::new(TOKEN_PATTERN).unwrap();
+
+    let mut errors: Vec<ParseError> = Vec::new();
+    let mut passports: Vec<Record> = Vec::new();
+    for group in line_groups(input.as_bytes()) {
+        // Reading from a string can't fail.
+        let group = group.unwrap();
+        passports.push(read_record(&group, mode, schema, &re, &mut errors));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    return Ok(passports);
+}
+
+pub struct PassportBatch {
+    passports: Vec<Record>,
+    schema: Schema,
+}
+
+impl PassportBatch {
+    /// Reads a batch to be validated against `schema`. Strict mode also uses
+    /// the schema to decide which fields are known.
+    pub fn parse_with(input: &str, mode: ParseMode, schema: Schema) -> Result<PassportBatch, Vec<ParseError>> {
+        return Ok(PassportBatch {
+            passports: read_passports(input, mode, &schema)?,
+            schema: schema,
+        });
+    }
+
+    pub fn records(&self) -> &[Record] {
+        return &self.passports;
+    }
+
+    pub fn schema(&self) -> &Schema {
+        return &self.schema;
+    }
+
+    /// Writes the batch back out in the puzzle's format, with each passport's
+    /// fields in canonical order. Passports left with no fields after
+    /// stripping invalid ones are dropped.
+    pub fn normalise(&self, layout: Layout, strip_invalid: bool) -> String {
+        let separator = match layout {
+            Layout::Line => " ",
+            Layout::Lines => "\n",
+        };
+
+        let passports: Vec<String> = self
+            .passports
+            .iter()
+            .map(|record| record.canonical_fields(&self.schema, strip_invalid))
+            .filter(|fields| !fields.is_empty())
+            .map(|fields| {
+                let pairs: Vec<String> = fields.iter().map(|(key, value)| format!("{}:{}", key, value)).collect();
+                return pairs.join(separator) + "\n";
+            })
+            .collect();
+        return passports.join("\n");
+    }
+
+    /// Counts the passports with every required field, whatever the values
+    /// are (part 1).
+    pub fn count_complete(&self) -> usize {
+        return self
+            .passports
+            .iter()
+            .filter(|p| self.schema.has_required_fields(&p.fields))
+            .count();
+    }
+
+    /// Counts the passports that pass every rule in the schema (part 2).
+    pub fn count_valid(&self) -> usize {
+        return self.passports.iter().filter(|p| self.schema.is_valid(&p.fields)).count();
+    }
+
+    /// The passports that pass the batch's schema, with typed fields.
+    pub fn passports(&self) -> Vec<Passport> {
+        return self
+            .passports
+            .iter()
+            .filter_map(|r| Passport::validate(r, &self.schema).ok())
+            .collect();
+    }
+
+    /// Every passport missing a required field, with only those violations,
+    /// in batch order.
+    pub fn incomplete(&self) -> Vec<Rejection<'_>> {
+        return self.reject(|violation| matches!(violation, Violation::Missing { .. }));
+    }
+
+    /// Every passport that fails validation, in batch order.
+    pub fn rejections(&self) -> Vec<Rejection<'_>> {
+        return self.reject(|_| true);
+    }
+
+    fn reject<F: Fn(&Violation) -> bool>(&self, keep: F) -> Vec<Rejection<'_>> {
+        return self
+            .passports
+            .iter()
+            .enumerate()
+            .filter_map(|(index, record)| {
+                let mut violations = self.schema.violations(&record.fields);
+                violations.retain(|v| keep(v));
+                if violations.is_empty() {
+                    return None;
+                }
+                return Some(Rejection {
+                    index: index,
+                    record: record,
+                    violations: violations,
+                });
+            })
+            .collect();
+    }
+}
+
+impl Solution for PassportBatch {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+",
+            options: &[],
+        },
+        Example {
+            name: "invalid",
+            input: "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+",
+            options: &[],
+        },
+        Example {
+            name: "valid",
+            input: "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+",
+            options: &[],
+        },
+    ];
+
+    fn parse(input: &str) -> Result<PassportBatch, ParseError> {
+        return PassportBatch::parse_with(input, ParseMode::Lenient, Schema::default())
+            .map_err(|errors| errors.into_iter().next().unwrap());
+    }
+
+    /// `schema` names a TOML file of validation rules to use instead of the
+    /// default ones, and `required` and `optional` are comma-separated lists
+    /// of fields to make required or optional on top of it.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        if let Some(path) = options.get::<String>("schema")? {
+            self.schema = Schema::from_file(&path).map_err(|message| OptionError {
+                name: String::from("schema"),
+                message: message,
+            })?;
+        }
+        return self.schema.configure(options);
+    }
+
+    fn part1(&self) -> usize {
+        return self.count_complete();
+    }
+
+    fn part2(&self) -> usize {
+        return self.count_valid();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Input;
+    use std::convert::TryFrom;
+
+    fn example(name: &str, options: &[(&str, &str)]) -> PassportBatch {
+        let input = Input::Example(name.to_string());
+        return PassportBatch::from_input(&input, &options.iter().cloned().collect()).unwrap();
+    }
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(example("example", &[]).part1(), 2);
+        let incomplete: Vec<usize> = example("example", &[]).incomplete().iter().map(|r| r.index).collect();
+        assert_eq!(incomplete, vec![1, 3]);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(example("invalid", &[]).part2(), 0);
+        assert_eq!(example("valid", &[]).part2(), 4);
+
+        // Every invalid passport has all the required fields.
+        assert_eq!(example("invalid", &[]).part1(), 4);
+    }
+
+    #[test]
+    fn configured_fields() {
+        assert_eq!(example("example", &[("required", "cid")]).part1(), 1);
+        assert_eq!(example("example", &[("optional", "hgt")]).part1(), 3);
+        assert_eq!(example("example", &[("optional", "byr,hgt")]).part1(), 4);
+        assert_eq!(example("valid", &[("required", "cid")]).part2(), 2);
+        assert_eq!(example("valid", &[("required", "nick")]).part2(), 0);
+    }
+
+    const BATCH: &str = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+";
+
+    #[test]
+    fn rejections() {
+        let batch = PassportBatch::parse(BATCH).unwrap();
+        let lines: Vec<(usize, usize)> = batch.records().iter().map(|r| (r.first_line, r.last_line)).collect();
+        assert_eq!(lines, vec![(1, 2), (4, 6), (9, 10), (12, 13)]);
+
+        let rejections = batch.rejections();
+        let indices: Vec<usize> = rejections.iter().map(|r| r.index).collect();
+        assert_eq!(indices, vec![0, 1, 2]);
+        assert_eq!(rejections[0].violations.len(), 3);
+        assert_eq!(
+            rejections[1].violations,
+            vec![Violation::OutOfRange {
+                field: String::from("eyr"),
+                value: 1967,
+                unit: None,
+                min: 2020,
+                max: 2030,
+            }]
+        );
+    }
+
+    #[test]
+    fn strict_parsing() {
+        let input = "byr:1990 byr:1991\nfoo:bar\n\nhgt:170cm junk pid::1\n";
+        let lenient = PassportBatch::parse_with(input, ParseMode::Lenient, Schema::default()).unwrap();
+        assert_eq!(lenient.records()[0].fields["byr"], "1991");
+        assert_eq!(lenient.records()[0].fields["foo"], "bar");
+
+        let errors = PassportBatch::parse_with(input, ParseMode::Strict, Schema::default())
+            .err()
+            .unwrap();
+        let found: Vec<(usize, usize, String)> = errors
+            .iter()
+            .map(|e| (e.line, e.column, e.offending().to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, 10, String::from("byr:1991")),
+                (2, 1, String::from("foo")),
+                (4, 11, String::from("junk")),
+                (4, 16, String::from("pid::1")),
+            ]
+        );
+
+        assert!(PassportBatch::parse_with(BATCH, ParseMode::Strict, Schema::default()).is_ok());
+    }
+
+    #[test]
+    fn streaming() {
+        let crlf = BATCH.replace('\n', "\r\n").replace("\r\n\r\n", "\r\n   \r\n");
+        let records: Vec<Record> = read_records(crlf.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(records, PassportBatch::parse(BATCH).unwrap().records());
+    }
+
+    #[test]
+    fn normalise() {
+        let batch = PassportBatch::parse("zz:1 pid:000000001 byr:1990\nhgt:2m aa:x\n\nhgt:60in\n").unwrap();
+        assert_eq!(
+            batch.normalise(Layout::Line, false),
+            "byr:1990 hgt:2m pid:000000001 aa:x zz:1\n\nhgt:60in\n"
+        );
+        assert_eq!(
+            batch.normalise(Layout::Lines, true),
+            "byr:1990\npid:000000001\n\nhgt:60in\n"
+        );
+
+        // What's left is exactly what strict parsing accepts.
+        let stripped = batch.normalise(Layout::Line, true);
+        assert!(PassportBatch::parse_with(&stripped, ParseMode::Strict, Schema::default()).is_ok());
+
+        // Normalising doesn't change what the batch holds.
+        let example = PassportBatch::parse(PassportBatch::EXAMPLES[0].input).unwrap();
+        for &layout in &[Layout::Line, Layout::Lines] {
+            let again = PassportBatch::parse(&example.normalise(layout, false)).unwrap();
+            let fields = |b: &PassportBatch| b.records().iter().map(|r| r.fields.clone()).collect::<Vec<_>>();
+            assert_eq!(fields(&again), fields(&example));
+        }
+    }
+
+    #[test]
+    fn typed_passports() {
+        let batch = PassportBatch::parse(BATCH).unwrap();
+        let passports = batch.passports();
+        assert_eq!(passports.len(), 1);
+
+        let passport = &passports[0];
+        assert_eq!(passport.birth_year, BirthYear(1980));
+        assert_eq!(passport.height.unit, HeightUnit::In);
+        assert!((passport.height.centimetres() - 187.96).abs() < 1e-9);
+        assert_eq!(passport.hair_colour, HairColour(Rgb { r: 0x62, g: 0x3a, b: 0x2f }));
+        assert_eq!(passport.eye_colour, EyeColour::Green);
+        assert_eq!(passport.passport_id.to_string(), "087499704");
+        assert_eq!(passport.country_id, None);
+
+        assert_eq!(passports.len(), batch.count_valid());
+
+        // The typed conversion only checks shapes; the ranges come from the schema.
+        let record = batch.records().iter().find(|r| r.fields["byr"] == "1946").unwrap();
+        assert!(Passport::try_from(record).is_ok());
+        assert_eq!(
+            Passport::validate(record, batch.schema()).err().unwrap(),
+            Schema::default().violations(&record.fields)
+        );
+
+        // Loosening the schema lets the same record through.
+        let mut schema = Schema::default();
+        schema.fields.iter_mut().find(|f| f.name == "eyr").unwrap().kind = FieldType::Any;
+        let batch = PassportBatch::parse_with(BATCH, ParseMode::Lenient, schema).unwrap();
+        assert_eq!(batch.passports().len(), 2);
+        assert_eq!(batch.passports()[0].expiration_year, ExpirationYear(1967));
+    }
+}
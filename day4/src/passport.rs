@@ -0,0 +1,217 @@
+use crate::{Record, Schema, Violation};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BirthYear(pub u16);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IssueYear(pub u16);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExpirationYear(pub u16);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HeightUnit {
+    Cm,
+    In,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Height {
+    pub value: u16,
+    pub unit: HeightUnit,
+}
+
+impl Height {
+    pub fn centimetres(&self) -> f64 {
+        match self.unit {
+            HeightUnit::Cm => return self.value as f64,
+            HeightUnit::In => return self.value as f64 * 2.54,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HairColour(pub Rgb);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EyeColour {
+    Amber,
+    Blue,
+    Brown,
+    Grey,
+    Green,
+    Hazel,
+    Other,
+}
+
+const EYE_COLOURS: [(&str, EyeColour); 7] = [
+    ("amb", EyeColour::Amber),
+    ("blu", EyeColour::Blue),
+    ("brn", EyeColour::Brown),
+    ("gry", EyeColour::Grey),
+    ("grn", EyeColour::Green),
+    ("hzl", EyeColour::Hazel),
+    ("oth", EyeColour::Other),
+];
+
+/// A nine digit passport number, which may have leading zeroes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PassportId(pub u32);
+
+impl fmt::Display for PassportId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{:09}", self.0);
+    }
+}
+
+/// The puzzle doesn't say what a country ID looks like, so it is kept as
+/// written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CountryId(pub String);
+
+/// A passport with each field parsed into its own type. Converting a
+/// `Record` only checks that each field has the shape its type needs; which
+/// values are acceptable is up to a `Schema`, which `Passport::validate`
+/// applies first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Passport {
+    pub birth_year: BirthYear,
+    pub issue_year: IssueYear,
+    pub expiration_year: ExpirationYear,
+    pub height: Height,
+    pub hair_colour: HairColour,
+    pub eye_colour: EyeColour,
+    pub passport_id: PassportId,
+    pub country_id: Option<CountryId>,
+}
+
+impl Passport {
+    /// Checks `record` against `schema`, then converts it.
+    pub fn validate(record: &Record, schema: &Schema) -> Result<Passport, Vec<Violation>> {
+        let violations = schema.violations(&record.fields);
+        if !violations.is_empty() {
+            return Err(violations);
+        }
+        return Passport::try_from(record);
+    }
+}
+
+/// Reads typed fields out of a record, keeping every violation rather than
+/// stopping at the first.
+struct FieldReader<'a> {
+    fields: &'a HashMap<String, String>,
+    violations: Vec<Violation>,
+}
+
+impl<'a> FieldReader<'a> {
+    fn read<T, F>(&mut self, field: &str, parse: F) -> Option<T>
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        let result = match self.fields.get(field) {
+            None => Err(Violation::Missing {
+                field: field.to_string(),
+            }),
+            Some(value) => parse(value).ok_or_else(|| Violation::Unparsable {
+                field: field.to_string(),
+                value: value.to_string(),
+            }),
+        };
+        match result {
+            Ok(value) => return Some(value),
+            Err(violation) => {
+                self.violations.push(violation);
+                return None;
+            }
+        }
+    }
+}
+
+fn height(value: &str) -> Option<Height> {
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let unit = match unit {
+        "cm" => HeightUnit::Cm,
+        "in" => HeightUnit::In,
+        _ => return None,
+    };
+    return Some(Height {
+        value: number.parse().ok()?,
+        unit: unit,
+    });
+}
+
+fn hair_colour(value: &str) -> Option<HairColour> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+    return Some(HairColour(Rgb {
+        r: byte(0),
+        g: byte(2),
+        b: byte(4),
+    }));
+}
+
+fn eye_colour(value: &str) -> Option<EyeColour> {
+    return EYE_COLOURS.iter().find(|(name, _)| *name == value).map(|&(_, colour)| colour);
+}
+
+/// Up to nine digits, so that the ID can be shown with its leading zeroes.
+fn passport_id(value: &str) -> Option<PassportId> {
+    if value.len() > 9 || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    return Some(PassportId(value.parse().ok()?));
+}
+
+/// Fails with a `Missing` or `Unparsable` violation for each field that
+/// isn't there or can't be read as its type. Other rules, such as the
+/// puzzle's ranges, are left to `Passport::validate`.
+impl TryFrom<&Record> for Passport {
+    type Error = Vec<Violation>;
+
+    fn try_from(record: &Record) -> Result<Passport, Vec<Violation>> {
+        let mut reader = FieldReader {
+            fields: &record.fields,
+            violations: vec![],
+        };
+
+        let birth_year = reader.read("byr", |v| v.parse().ok().map(BirthYear));
+        let issue_year = reader.read("iyr", |v| v.parse().ok().map(IssueYear));
+        let expiration_year = reader.read("eyr", |v| v.parse().ok().map(ExpirationYear));
+        let height = reader.read("hgt", height);
+        let hair_colour = reader.read("hcl", hair_colour);
+        let eye_colour = reader.read("ecl", eye_colour);
+        let passport_id = reader.read("pid", passport_id);
+        let country_id = record.fields.get("cid").map(|cid| CountryId(cid.clone()));
+
+        match (birth_year, issue_year, expiration_year, height, hair_colour, eye_colour, passport_id) {
+            (Some(byr), Some(iyr), Some(eyr), Some(hgt), Some(hcl), Some(ecl), Some(pid)) => {
+                return Ok(Passport {
+                    birth_year: byr,
+                    issue_year: iyr,
+                    expiration_year: eyr,
+                    height: hgt,
+                    hair_colour: hcl,
+                    eye_colour: ecl,
+                    passport_id: pid,
+                    country_id: country_id,
+                });
+            }
+            _ => return Err(reader.violations),
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use common::{OptionError, Options};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use toml::Value;
+
+/// The rules shipped with the puzzle.
+const DEFAULT_SCHEMA: &str = include_str!("../schema.toml");
+
+/// What a field's value must look like.
+#[derive(Clone, Debug)]
+pub enum FieldType {
+    Any,
+    /// A whole number in `min..=max`.
+    Int { min: i64, max: i64 },
+    /// A whole number followed by a unit, with a range for each unit.
+    Measure { units: Vec<(String, i64, i64)> },
+    /// `pattern` must match the whole value; `re` is it compiled with
+    /// anchors.
+    Regex { pattern: String, re: Regex },
+    Enum(Vec<String>),
+}
+
+/// One reason a passport is invalid.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+    Missing { field: String },
+    /// The value isn't a number, or a number and a known unit.
+    Unparsable { field: String, value: String },
+    OutOfRange {
+        field: String,
+        value: i64,
+        unit: Option<String>,
+        min: i64,
+        max: i64,
+    },
+    Mismatch { field: String, value: String, pattern: String },
+    NotAllowed { field: String, value: String, allowed: Vec<String> },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Violation::Missing { field } => return write!(f, "{} is missing", field),
+            Violation::Unparsable { field, value } => {
+                return write!(f, "{} '{}' is not a valid number", field, value);
+            }
+            Violation::OutOfRange {
+                field,
+                value,
+                unit,
+                min,
+                max,
+            } => {
+                let unit = unit.as_deref().unwrap_or("");
+                return write!(
+                    f,
+                    "{} {}{} is outside {}{}..={}{}",
+                    field, value, unit, min, unit, max, unit
+                );
+            }
+            Violation::Mismatch { field, value, pattern } => {
+                return write!(f, "{} '{}' does not match {}", field, value, pattern);
+            }
+            Violation::NotAllowed { field, value, allowed } => {
+                return write!(f, "{} '{}' is not one of {}", field, value, allowed.join(", "));
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldRule {
+    pub name: String,
+    pub required: bool,
+    pub kind: FieldType,
+}
+
+impl FieldRule {
+    fn unparsable(&self, value: &str) -> Violation {
+        return Violation::Unparsable {
+            field: self.name.clone(),
+            value: value.to_string(),
+        };
+    }
+
+    fn in_range(&self, value: i64, unit: Option<&str>, min: i64, max: i64) -> Result<(), Violation> {
+        if (min..=max).contains(&value) {
+            return Ok(());
+        }
+        return Err(Violation::OutOfRange {
+            field: self.name.clone(),
+            value: value,
+            unit: unit.map(String::from),
+            min: min,
+            max: max,
+        });
+    }
+
+    /// Splits a measure into its number and the unit it's in.
+    fn measure<'a>(units: &'a [(String, i64, i64)], value: &str) -> Option<(i64, &'a (String, i64, i64))> {
+        let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+        let number = number.parse::<i64>().ok()?;
+        return Some((number, units.iter().find(|(name, _, _)| name == unit)?));
+    }
+
+    /// Whether `value` passes this rule. Unlike `check`, this never allocates,
+    /// so it's the one to use when only the answer is wanted.
+    pub fn is_valid(&self, value: &str) -> bool {
+        match &self.kind {
+            FieldType::Any => return true,
+            FieldType::Int { min, max } => return value.parse::<i64>().is_ok_and(|n| (*min..=*max).contains(&n)),
+            FieldType::Measure { units } => {
+                return FieldRule::measure(units, value).is_some_and(|(n, (_, min, max))| (*min..=*max).contains(&n));
+            }
+            FieldType::Regex { re, .. } => return re.is_match(value),
+            FieldType::Enum(values) => return values.iter().any(|v| v == value),
+        }
+    }
+
+    /// Like `is_valid`, but says why `value` fails.
+    pub fn check(&self, value: &str) -> Result<(), Violation> {
+        if self.is_valid(value) {
+            return Ok(());
+        }
+
+        match &self.kind {
+            FieldType::Any => return Ok(()),
+            FieldType::Int { min, max } => {
+                let number = value.parse::<i64>().map_err(|_| self.unparsable(value))?;
+                return self.in_range(number, None, *min, *max);
+            }
+            FieldType::Measure { units } => {
+                let (number, (unit, min, max)) =
+                    FieldRule::measure(units, value).ok_or_else(|| self.unparsable(value))?;
+                return self.in_range(number, Some(unit), *min, *max);
+            }
+            FieldType::Regex { pattern, .. } => {
+                return Err(Violation::Mismatch {
+                    field: self.name.clone(),
+                    value: value.to_string(),
+                    pattern: pattern.clone(),
+                });
+            }
+            FieldType::Enum(values) => {
+                return Err(Violation::NotAllowed {
+                    field: self.name.clone(),
+                    value: value.to_string(),
+                    allowed: values.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// The fields a passport should have, and how to validate each of them.
+#[derive(Clone, Debug)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+fn integer(table: &Value, key: &str, field: &str) -> Result<i64, String> {
+    return table
+        .get(key)
+        .and_then(Value::as_integer)
+        .ok_or_else(|| format!("field '{}' needs an integer '{}'", field, key));
+}
+
+/// Reads a `[min, max]` pair.
+fn range(value: &Value) -> Option<(i64, i64)> {
+    match value.as_array().map(Vec::as_slice) {
+        Some([min, max]) => return Some((min.as_integer()?, max.as_integer()?)),
+        _ => return None,
+    }
+}
+
+fn parse_type(table: &Value, name: &str) -> Result<FieldType, String> {
+    let kind = match table.get("type") {
+        None => "any",
+        Some(kind) => kind.as_str().ok_or_else(|| format!("field '{}' has a non-string type", name))?,
+    };
+
+    match kind {
+        "any" => return Ok(FieldType::Any),
+        "int" => {
+            return Ok(FieldType::Int {
+                min: integer(table, "min", name)?,
+                max: integer(table, "max", name)?,
+            });
+        }
+        "measure" => {
+            let units = table
+                .get("units")
+                .and_then(Value::as_table)
+                .ok_or_else(|| format!("field '{}' needs a table of units", name))?;
+            let mut ranges = vec![];
+            for (unit, bounds) in units {
+                let (min, max) = range(bounds)
+                    .ok_or_else(|| format!("unit '{}' of field '{}' needs a [min, max] range", unit, name))?;
+                ranges.push((unit.clone(), min, max));
+            }
+            return Ok(FieldType::Measure { units: ranges });
+        }
+        "regex" => {
+            let pattern = table
+                .get("pattern")
+                .and_then(Value::as_str)
+                .ok_or_else(|| format!("field '{}' needs a pattern", name))?;
+            let re = Regex::new(&format!("^(?:{})$", pattern))
+                .map_err(|e| format!("field '{}' has an invalid pattern: {}", name, e))?;
+            return Ok(FieldType::Regex {
+                pattern: pattern.to_string(),
+                re: re,
+            });
+        }
+        "enum" => {
+            let values = table
+                .get("values")
+                .and_then(Value::as_array)
+                .and_then(|values| values.iter().map(|v| v.as_str().map(String::from)).collect())
+                .ok_or_else(|| format!("field '{}' needs a list of string values", name))?;
+            return Ok(FieldType::Enum(values));
+        }
+        other => return Err(format!("field '{}' has unknown type '{}'", name, other)),
+    }
+}
+
+impl Schema {
+    /// Reads a schema from TOML, with one `[[field]]` table per field. See
+    /// the default `schema.toml` for the format.
+    pub fn parse(text: &str) -> Result<Schema, String> {
+        let root = text.parse::<Value>().map_err(|e| e.to_string())?;
+        let tables = match root.get("field") {
+            None => return Ok(Schema { fields: vec![] }),
+            Some(tables) => tables.as_array().ok_or("'field' must be an array of tables")?,
+        };
+
+        let mut fields = vec![];
+        for table in tables {
+            let name = table
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or("every field needs a name")?;
+            let required = match table.get("required") {
+                None => true,
+                Some(required) => required
+                    .as_bool()
+                    .ok_or_else(|| format!("field '{}' has a non-boolean 'required'", name))?,
+            };
+            fields.push(FieldRule {
+                name: name.to_string(),
+                required: required,
+                kind: parse_type(table, name)?,
+            });
+        }
+
+        return Ok(Schema { fields: fields });
+    }
+
+    pub fn from_file(path: &str) -> Result<Schema, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+        return Schema::parse(&text).map_err(|e| format!("{}: {}", path, e));
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldRule> {
+        return self.fields.iter().find(|rule| rule.name == name);
+    }
+
+    /// Makes `name` required or optional, adding it with no other rules if
+    /// the schema doesn't have it yet.
+    pub fn set_required(&mut self, name: &str, required: bool) {
+        match self.fields.iter_mut().find(|rule| rule.name == name) {
+            Some(rule) => rule.required = required,
+            None => self.fields.push(FieldRule {
+                name: name.to_string(),
+                required: required,
+                kind: FieldType::Any,
+            }),
+        }
+    }
+
+    /// Applies the `required` and `optional` options, comma-separated lists of
+    /// fields to make required or optional.
+    pub fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        for &(option, required) in &[("required", true), ("optional", false)] {
+            if let Some(fields) = options.get::<String>(option)? {
+                for field in fields.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+                    self.set_required(field, required);
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    /// Whether every required field is present, whatever its value.
+    pub fn has_required_fields(&self, passport: &HashMap<String, String>) -> bool {
+        return self
+            .fields
+            .iter()
+            .all(|rule| !rule.required || passport.contains_key(&rule.name));
+    }
+
+    /// Whether every required field is present and every field present is
+    /// valid. Fields the schema doesn't mention are ignored.
+    pub fn is_valid(&self, passport: &HashMap<String, String>) -> bool {
+        return self.fields.iter().all(|rule| match passport.get(&rule.name) {
+            None => !rule.required,
+            Some(value) => rule.is_valid(value),
+        });
+    }
+
+    /// Every reason `passport` is invalid, in schema order.
+    pub fn violations(&self, passport: &HashMap<String, String>) -> Vec<Violation> {
+        let mut violations = vec![];
+        for rule in &self.fields {
+            match passport.get(&rule.name) {
+                None if rule.required => violations.push(Violation::Missing {
+                    field: rule.name.clone(),
+                }),
+                None => (),
+                Some(value) => {
+                    if let Err(violation) = rule.check(value) {
+                        violations.push(violation);
+                    }
+                }
+            }
+        }
+        return violations;
+    }
+}
+
+impl Default for Schema {
+    fn default() -> Schema {
+        return Schema::parse(DEFAULT_SCHEMA).expect("the default schema is invalid");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(field: &str, value: &str) -> bool {
+        let schema = Schema::default();
+        let rule = schema.fields.iter().find(|rule| rule.name == field).unwrap();
+        assert_eq!(rule.is_valid(value), rule.check(value).is_ok());
+        return rule.is_valid(value);
+    }
+
+    #[test]
+    fn default_rules() {
+        assert!(check("byr", "2002"));
+        assert!(!check("byr", "2003"));
+        assert!(check("hgt", "60in"));
+        assert!(check("hgt", "190cm"));
+        assert!(!check("hgt", "190in"));
+        assert!(!check("hgt", "190"));
+        assert!(check("hcl", "#123abc"));
+        assert!(!check("hcl", "#123abz"));
+        assert!(!check("hcl", "123abc"));
+        assert!(check("ecl", "brn"));
+        assert!(!check("ecl", "wat"));
+        assert!(check("pid", "000000001"));
+        assert!(!check("pid", "0123456789"));
+    }
+
+    #[test]
+    fn violations() {
+        let passport: HashMap<String, String> =
+            [("byr", "2003"), ("hgt", "74"), ("hcl", "#12345z"), ("ecl", "wat"), ("pid", "1")]
+                .iter()
+                .map(|&(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+        let violations: Vec<String> = Schema::default()
+            .violations(&passport)
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "byr 2003 is outside 1920..=2002",
+                "iyr is missing",
+                "eyr is missing",
+                "hgt '74' is not a valid number",
+                "hcl '#12345z' does not match #[0-9a-f]{6}",
+                "ecl 'wat' is not one of amb, blu, brn, gry, grn, hzl, oth",
+                "pid '1' does not match [0-9]{9}",
+            ]
+        );
+
+        let mut tall = HashMap::new();
+        tall.insert(String::from("hgt"), String::from("200cm"));
+        assert!(Schema::default().violations(&tall).contains(&Violation::OutOfRange {
+            field: String::from("hgt"),
+            value: 200,
+            unit: Some(String::from("cm")),
+            min: 150,
+            max: 193,
+        }));
+    }
+
+    #[test]
+    fn custom_schema() {
+        let schema = Schema::parse(
+            r#"
+            [[field]]
+            name = "hgt"
+            type = "measure"
+            units = { m = [1, 3] }
+
+            [[field]]
+            name = "nick"
+            required = false
+            type = "regex"
+            pattern = "[a-z]+"
+            "#,
+        )
+        .unwrap();
+
+        let passport: HashMap<String, String> = [("hgt", "2m"), ("byr", "1800")]
+            .iter()
+            .map(|&(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        assert!(schema.is_valid(&passport));
+
+        assert!(Schema::parse("[[field]]\nname = \"byr\"\ntype = \"int\"\nmin = 1").is_err());
+        assert!(Schema::parse("[[field]]\nname = \"x\"\ntype = \"date\"").is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{lenient_tokens, Schema, TOKEN_PATTERN};
+use regex::Regex;
+use std::io::{self, BufRead};
+
+/// The most fields a `Validator` can track.
+const MAX_FIELDS: usize = 128;
+
+/// Whether a passport passes each part of the puzzle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Verdict {
+    /// Every required field is present.
+    pub complete: bool,
+    /// Every required field is present and every known field is valid.
+    pub valid: bool,
+}
+
+/// Checks passports against a schema whose rules are compiled once, keeping
+/// track of the fields seen in a bitmask so that checking a passport never
+/// allocates.
+pub struct Validator {
+    schema: Schema,
+    required: u128,
+    token: Regex,
+}
+
+impl Validator {
+    pub fn new(schema: Schema) -> Result<Validator, String> {
+        if schema.fields.len() > MAX_FIELDS {
+            return Err(format!("a validator can check at most {} fields", MAX_FIELDS));
+        }
+
+        let required = schema
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.required)
+            .fold(0, |mask, (i, _)| mask | 1 << i);
+        return Ok(Validator {
+            schema: schema,
+            required: required,
+            token: Regex::new(TOKEN_PATTERN).unwrap(),
+        });
+    }
+
+    /// Checks one passport's fields. As when reading a batch, a field that
+    /// appears twice is judged on its last value.
+    pub fn check<'a, I: IntoIterator<Item = (&'a str, &'a str)>>(&self, fields: I) -> Verdict {
+        let mut tally = Tally::default();
+        for (key, value) in fields {
+            self.add(&mut tally, key, value);
+        }
+        return self.verdict(&tally);
+    }
+
+    /// Counts the complete and valid passports in a raw batch as it's read,
+    /// a line at a time into one buffer, without building any records.
+    /// Records are split up as `common::line_groups` does.
+    pub fn count<R: BufRead>(&self, mut reader: R) -> io::Result<(usize, usize)> {
+        let mut locations = self.token.capture_locations();
+        let mut line = String::new();
+        let mut complete = 0;
+        let mut valid = 0;
+        let mut tally: Option<Tally> = None;
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+
+            // A blank line, or the end of the batch, ends a passport.
+            if line.trim().is_empty() {
+                if let Some(tally) = tally.take() {
+                    let verdict = self.verdict(&tally);
+                    complete += verdict.complete as usize;
+                    valid += verdict.valid as usize;
+                }
+                if read == 0 {
+                    return Ok((complete, valid));
+                }
+                continue;
+            }
+
+            let tally = tally.get_or_insert_with(Tally::default);
+            lenient_tokens(&self.token, &mut locations, &line, |key, value| self.add(tally, key, value));
+        }
+    }
+
+    fn add(&self, tally: &mut Tally, key: &str, value: &str) {
+        if let Some(i) = self.schema.fields.iter().position(|rule| rule.name == key) {
+            tally.seen |= 1 << i;
+            if self.schema.fields[i].is_valid(value) {
+                tally.valid |= 1 << i;
+            } else {
+                tally.valid &= !(1 << i);
+            }
+        }
+    }
+
+    fn verdict(&self, tally: &Tally) -> Verdict {
+        let complete = tally.seen & self.required == self.required;
+        return Verdict {
+            complete: complete,
+            valid: complete && tally.valid == tally.seen,
+        };
+    }
+}
+
+/// The schema fields seen in a passport so far, and which of them are valid.
+#[derive(Default)]
+struct Tally {
+    seen: u128,
+    valid: u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PassportBatch;
+    use common::Solution;
+
+    #[test]
+    fn matches_batch() {
+        let validator = Validator::new(Schema::default()).unwrap();
+        for example in PassportBatch::EXAMPLES {
+            let batch = PassportBatch::parse(example.input).unwrap();
+            assert_eq!(validator.count(example.input.as_bytes()).unwrap(), (batch.part1(), batch.part2()));
+
+            // Split up the same way with CRLF endings and whitespace-only separators.
+            let crlf = example.input.replace("\n\n", "\n \t\n").replace('\n', "\r\n");
+            assert_eq!(validator.count(crlf.as_bytes()).unwrap(), (batch.part1(), batch.part2()));
+
+            for record in batch.records() {
+                let fields = record.fields.iter().map(|(k, v)| (k.as_str(), v.as_str()));
+                let verdict = validator.check(fields);
+                assert_eq!(verdict.valid, batch.schema().is_valid(&record.fields));
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_fields() {
+        let validator = Validator::new(Schema::default()).unwrap();
+        let fields = [
+            ("byr", "1800"),
+            ("iyr", "2015"),
+            ("eyr", "2025"),
+            ("hgt", "180cm"),
+            ("hcl", "#123abc"),
+            ("ecl", "brn"),
+            ("pid", "000000001"),
+            ("byr", "1980"),
+        ];
+        assert!(validator.check(fields.iter().cloned()).valid);
+        assert!(!validator.check(fields.iter().rev().cloned()).valid);
+    }
+}
@@ -0,0 +1,281 @@
+use common::{Example, ParseError, ParseErrorKind, Solution};
+use std::fmt;
+
+const ROW_CHARS: usize = 7;
+const CODE_LEN: usize = ROW_CHARS + 3;
+
+const ROWS: u16 = 1 << ROW_CHARS;
+const COLUMNS: u16 = 1 << (CODE_LEN - ROW_CHARS);
+
+/// A seat on the plane: one of 128 rows of 8 columns.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Seat {
+    row: u16,
+    column: u16,
+}
+
+impl Seat {
+    /// The seat at `row` and `column`, if the plane has one there.
+    pub fn new(row: u16, column: u16) -> Option<Seat> {
+        if row >= ROWS || column >= COLUMNS {
+            return None;
+        }
+        return Some(Seat {
+            row: row,
+            column: column,
+        });
+    }
+
+    pub fn row(&self) -> u16 {
+        return self.row;
+    }
+
+    pub fn column(&self) -> u16 {
+        return self.column;
+    }
+
+    pub fn id(&self) -> u16 {
+        return self.row * COLUMNS + self.column;
+    }
+
+    /// The seat with ID `id`, if the plane has one.
+    pub fn from_id(id: u16) -> Option<Seat> {
+        return Seat::new(id / COLUMNS, id % COLUMNS);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodeError {
+    /// The code isn't ten characters long; holds how long it is.
+    Length(usize),
+    /// A character that doesn't belong at the 0-based `position`.
+    Character { position: usize, found: char },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::Length(len) => return write!(f, "expected {} characters, found {}", CODE_LEN, len),
+            DecodeError::Character { position, found } => {
+                let expected = if *position < ROW_CHARS { "F or B" } else { "L or R" };
+                return write!(f, "expected {} at position {}, found '{}'", expected, position + 1, found);
+            }
+        }
+    }
+}
+
+/// A boarding pass, whose code is the binary seat ID spelled with F and L for
+/// 0 and B and R for 1: seven bits of row followed by three of column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoardingPass {
+    pub code: String,
+    pub seat: Seat,
+}
+
+impl BoardingPass {
+    pub fn decode(code: &str) -> Result<Seat, DecodeError> {
+        let len = code.chars().count();
+        if len != CODE_LEN {
+            return Err(DecodeError::Length(len));
+        }
+
+        let mut id = 0;
+        for (position, c) in code.chars().enumerate() {
+            let bit = match (position < ROW_CHARS, c) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                _ => {
+                    return Err(DecodeError::Character {
+                        position: position,
+                        found: c,
+                    });
+                }
+            };
+            id = id << 1 | bit;
+        }
+
+        // Ten bits always make the ID of a seat.
+        return Ok(Seat::from_id(id).unwrap());
+    }
+
+    pub fn encode(seat: Seat) -> String {
+        let id = seat.id();
+        return (0..CODE_LEN)
+            .map(|position| {
+                let bit = id >> (CODE_LEN - 1 - position) & 1;
+                match (position < ROW_CHARS, bit) {
+                    (true, 0) => return 'F',
+                    (true, _) => return 'B',
+                    (false, 0) => return 'L',
+                    (false, _) => return 'R',
+                }
+            })
+            .collect();
+    }
+
+    fn from_line(line_number: usize, line: &str) -> Result<BoardingPass, ParseError> {
+        let code = line.trim();
+        match BoardingPass::decode(code) {
+            Ok(seat) => {
+                return Ok(BoardingPass {
+                    code: code.to_string(),
+                    seat: seat,
+                });
+            }
+            Err(DecodeError::Length(_)) => {
+                let expected = String::from("seven F/B characters followed by three L/R characters");
+                return Err(ParseError::at(line_number, line, code, ParseErrorKind::Syntax(expected)));
+            }
+            Err(DecodeError::Character { position, .. }) => {
+                let (offset, c) = code.char_indices().nth(position).unwrap();
+                let expected = if position < ROW_CHARS { "F or B" } else { "L or R" };
+                let offending = &code[offset..offset + c.len_utf8()];
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    offending,
+                    ParseErrorKind::Syntax(String::from(expected)),
+                ));
+            }
+        }
+    }
+}
+
+/// The seat with no boarding pass, or that every seat between the first and
+/// last has one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MissingSeat {
+    Found(Seat),
+    NoGap,
+}
+
+impl fmt::Display for MissingSeat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MissingSeat::Found(seat) => return write!(f, "{}", seat.id()),
+            MissingSeat::NoGap => return write!(f, "no missing seat"),
+        }
+    }
+}
+
+pub struct BoardingPasses {
+    /// Sorted by seat ID.
+    passes: Vec<BoardingPass>,
+}
+
+impl BoardingPasses {
+    pub fn passes(&self) -> &[BoardingPass] {
+        return &self.passes;
+    }
+
+    pub fn highest_id(&self) -> u16 {
+        return self.passes.last().unwrap().seat.id();
+    }
+
+    /// The seat whose ID is missing from the passes, though the IDs either
+    /// side of it are there.
+    pub fn missing_seat(&self) -> Option<Seat> {
+        return self
+            .passes
+            .windows(2)
+            .find(|pair| pair[1].seat.id() == pair[0].seat.id() + 2)
+            .and_then(|pair| Seat::from_id(pair[0].seat.id() + 1));
+    }
+}
+
+impl Solution for BoardingPasses {
+    type Part1 = u16;
+    type Part2 = MissingSeat;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "\
+FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL
+",
+        options: &[],
+    }];
+
+    fn parse(input: &str) -> Result<BoardingPasses, ParseError> {
+        let mut passes: Vec<BoardingPass> = vec![];
+        for (idx, line) in input.lines().enumerate() {
+            passes.push(BoardingPass::from_line(idx + 1, line)?);
+        }
+
+        if passes.is_empty() {
+            return Err(ParseError::input(ParseErrorKind::Empty));
+        }
+
+        passes.sort_by_key(|pass| pass.seat.id());
+        return Ok(BoardingPasses { passes: passes });
+    }
+
+    fn part1(&self) -> u16 {
+        return self.highest_id();
+    }
+
+    fn part2(&self) -> MissingSeat {
+        match self.missing_seat() {
+            Some(seat) => return MissingSeat::Found(seat),
+            None => return MissingSeat::NoGap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let seats: Vec<(u16, u16, u16)> = BoardingPasses::EXAMPLES[0]
+            .input
+            .lines()
+            .map(|code| BoardingPass::decode(code).unwrap())
+            .map(|seat| (seat.row(), seat.column(), seat.id()))
+            .collect();
+        assert_eq!(seats, vec![(44, 5, 357), (70, 7, 567), (14, 7, 119), (102, 4, 820)]);
+
+        assert_eq!(BoardingPass::decode("FBFBBFF"), Err(DecodeError::Length(7)));
+        assert_eq!(
+            BoardingPass::decode("FBFBBFFRLB"),
+            Err(DecodeError::Character {
+                position: 9,
+                found: 'B',
+            })
+        );
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(BoardingPass::encode(Seat::new(44, 5).unwrap()), "FBFBBFFRLR");
+        assert_eq!(Seat::new(0, 9), None);
+        assert_eq!(Seat::new(128, 0), None);
+        for id in 0..1024 {
+            let seat = Seat::from_id(id).unwrap();
+            assert_eq!(BoardingPass::decode(&BoardingPass::encode(seat)), Ok(seat));
+        }
+        assert_eq!(Seat::from_id(1024), None);
+    }
+
+    #[test]
+    fn pt1_example() {
+        let passes = BoardingPasses::parse(BoardingPasses::EXAMPLES[0].input).unwrap();
+        assert_eq!(passes.part1(), 820);
+    }
+
+    #[test]
+    fn pt2_missing_seat() {
+        let passes = BoardingPasses::parse("FFFBBBFRRR\nFFFBBBFRRL\nFFFBBBBLLR\n").unwrap();
+        assert_eq!(passes.missing_seat(), Seat::new(15, 0));
+        assert_eq!(passes.part2().to_string(), "120");
+
+        let passes = BoardingPasses::parse(BoardingPasses::EXAMPLES[0].input).unwrap();
+        assert_eq!(passes.part2(), MissingSeat::NoGap);
+
+        let err = BoardingPasses::parse("FFFBBBFRRR\nFFFXBBFRRL\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
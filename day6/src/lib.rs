@@ -0,0 +1,128 @@
+use common::{line_groups, Example, ParseError, Solution};
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+/// The answers of one group: how many members answered yes to each question.
+struct Group {
+    answers: HashMap<char, i32>,
+    size: i32,
+}
+
+impl Group {
+    fn new() -> Group {
+        return Group {
+            answers: HashMap::new(),
+            size: 0,
+        };
+    }
+
+    fn anyone_count(&self) -> usize {
+        return self.answers.len();
+    }
+
+    fn everyone_count(&self) -> usize {
+        return self.answers.values().filter(|&&c| c == self.size).count();
+    }
+}
+
+/// The totals over every group, since neither part needs the groups
+/// themselves.
+pub struct CustomsForms {
+    anyone: usize,
+    everyone: usize,
+}
+
+impl CustomsForms {
+    /// Reads the groups one at a time, so that only one is held in memory
+    /// however large the input is.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<CustomsForms> {
+        let mut forms = CustomsForms {
+            anyone: 0,
+            everyone: 0,
+        };
+        for lines in line_groups(reader) {
+            let mut group = Group::new();
+            for line in &lines?.lines {
+                group.size += 1;
+
+                for c in line.chars().filter(|c| !c.is_whitespace()) {
+                    let count = group.answers.entry(c).or_insert(0);
+                    *count += 1;
+                }
+            }
+            forms.anyone += group.anyone_count();
+            forms.everyone += group.everyone_count();
+        }
+
+        return Ok(forms);
+    }
+}
+
+impl Solution for CustomsForms {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+",
+        options: &[],
+    }];
+
+    fn parse(input: &str) -> Result<CustomsForms, ParseError> {
+        // Lines are only unreadable if they aren't UTF-8, and a `str` is.
+        return Ok(CustomsForms::from_reader(input.as_bytes()).unwrap());
+    }
+
+    fn part1(&self) -> usize {
+        return self.anyone;
+    }
+
+    fn part2(&self) -> usize {
+        return self.everyone;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pt1_example() {
+        assert_eq!(CustomsForms::parse(CustomsForms::EXAMPLES[0].input).unwrap().part1(), 11);
+    }
+
+    #[test]
+    fn pt2_example() {
+        assert_eq!(CustomsForms::parse(CustomsForms::EXAMPLES[0].input).unwrap().part2(), 6);
+    }
+
+    #[test]
+    fn crlf_and_blank_lines() {
+        let input = CustomsForms::EXAMPLES[0].input.replace('\n', " \r\n");
+        let forms = CustomsForms::parse(&format!("\r\n{}\r\n\t\r\n", input)).unwrap();
+        assert_eq!((forms.part1(), forms.part2()), (11, 6));
+    }
+
+    #[test]
+    fn from_reader() {
+        let forms = CustomsForms::from_reader(CustomsForms::EXAMPLES[0].input.as_bytes()).unwrap();
+        assert_eq!((forms.part1(), forms.part2()), (11, 6));
+        assert!(CustomsForms::from_reader(&b"ab\n\xff\n"[..]).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use common::{Example, ParseError, ParseErrorKind, Solution};
+use regex::Regex;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct BagRules {
+    /// For each bag colour, the colours and counts of the bags it must contain.
+    contents: HashMap<String, Vec<(String, i64)>>,
+}
+
+impl BagRules {
+    pub fn from_lines(lines: &[String]) -> Result<BagRules, ParseError> {
+        let top_re = Regex::new(r"^(?P<colour>.*) bags contain (?P<remainder>.*)$").unwrap();
+        let sub_re = Regex::new(r"\s?(?P<count>\d+) (?P<colour>[^,\.]*) bags?(,|\.)").unwrap();
+
+        let mut contents: HashMap<String, Vec<(String, i64)>> = HashMap::new();
+        for (idx, line) in lines.iter().enumerate() {
+            let top_caps = match top_re.captures(line) {
+                Some(caps) => caps,
+                None => {
+                    let expected = String::from("`<colour> bags contain <contents>`");
+                    return Err(ParseError::line(idx + 1, line, ParseErrorKind::Syntax(expected)));
+                }
+            };
+            let container_color = top_caps["colour"].to_string();
+            let remainder = top_caps.name("remainder").unwrap().as_str();
+
+            let entry = contents.entry(container_color).or_default();
+            for caps in sub_re.captures_iter(remainder) {
+                let contained_color = caps["colour"].to_string();
+                let count_text = caps.name("count").unwrap().as_str();
+                let count = count_text.parse::<i64>().map_err(|_| {
+                    return ParseError::at(idx + 1, line, count_text, ParseErrorKind::InvalidNumber);
+                })?;
+                entry.push((contained_color, count));
+            }
+
+            if entry.is_empty() && remainder != "no other bags." {
+                let expected = String::from("a list of bags, or `no other bags.`");
+                return Err(ParseError::at(idx + 1, line, remainder, ParseErrorKind::Syntax(expected)));
+            }
+        }
+
+        return Ok(BagRules { contents: contents });
+    }
+
+    /// Counts the bag colours that can eventually contain a `colour` bag.
+    pub fn count_containers(&self, colour: &str) -> usize {
+        let mut bags_in: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (container_color, contained) in &self.contents {
+            for (contained_color, _) in contained {
+                let entry = bags_in.entry(contained_color).or_default();
+                entry.push(container_color);
+            }
+        }
+
+        let bags_in = bags_in;
+        let mut queue: VecDeque<&str> = match bags_in.get(colour) {
+            Some(bags) => bags.iter().cloned().collect(),
+            None => return 0,
+        };
+        let mut visited: HashSet<&str> = queue.iter().cloned().collect();
+        let mut count = queue.len();
+        while let Some(bag) = queue.pop_front() {
+            if !bags_in.contains_key(bag) {
+                continue;
+            }
+
+            for &b in &bags_in[bag] {
+                if !visited.contains(b) {
+                    queue.push_back(b);
+                    visited.insert(b);
+                    count += 1;
+                }
+            }
+        }
+
+        return count;
+    }
+
+    /// Counts the bags that a `colour` bag must contain. A colour with no
+    /// rule contains nothing, as it would inside another bag.
+    pub fn count_contained(&self, colour: &str) -> i64 {
+        let mut queue: VecDeque<(&str, i64)> = VecDeque::new();
+        let mut count = 0;
+        for (b, c) in self.contents.get(colour).into_iter().flatten() {
+            queue.push_back((b, *c));
+        }
+
+        while let Some((container_bag, container_count)) = queue.pop_front() {
+            count += container_count;
+
+            if !self.contents.contains_key(container_bag) {
+                continue;
+            }
+
+            for (contained_bag, contained_count) in &self.contents[container_bag] {
+                queue.push_back((contained_bag, container_count * contained_count));
+            }
+        }
+
+        return count;
+    }
+}
+
+impl Solution for BagRules {
+    type Part1 = usize;
+    type Part2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example1",
+            input: "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+",
+            options: &[],
+        },
+        Example {
+            name: "example2",
+            input: "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+",
+            options: &[],
+        },
+    ];
+
+    fn parse(input: &str) -> Result<BagRules, ParseError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        return BagRules::from_lines(&lines);
+    }
+
+    fn part1(&self) -> usize {
+        return self.count_containers("shiny gold");
+    }
+
+    fn part2(&self) -> i64 {
+        return self.count_contained("shiny gold");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pt1_example() {
+        let lines = vec![
+            String::from("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+            String::from("dark orange bags contain 3 bright white bags, 4 muted yellow bags."),
+            String::from("bright white bags contain 1 shiny gold bag."),
+            String::from("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags."),
+            String::from("shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags."),
+            String::from("dark olive bags contain 3 faded blue bags, 4 dotted black bags."),
+            String::from("vibrant plum bags contain 5 faded blue bags, 6 dotted black bags."),
+            String::from("faded blue bags contain no other bags."),
+            String::from("dotted black bags contain no other bags."),
+        ];
+
+        let count = BagRules::from_lines(&lines).unwrap().part1();
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn pt2_example1() {
+        let lines = vec![
+            String::from("light red bags contain 1 bright white bag, 2 muted yellow bags."),
+            String::from("dark orange bags contain 3 bright white bags, 4 muted yellow bags."),
+            String::from("bright white bags contain 1 shiny gold bag."),
+            String::from("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags."),
+            String::from("shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags."),
+            String::from("dark olive bags contain 3 faded blue bags, 4 dotted black bags."),
+            String::from("vibrant plum bags contain 5 faded blue bags, 6 dotted black bags."),
+            String::from("faded blue bags contain no other bags."),
+            String::from("dotted black bags contain no other bags."),
+        ];
+
+        let count = BagRules::from_lines(&lines).unwrap().part2();
+        assert_eq!(count, 32);
+    }
+
+    #[test]
+    fn pt2_example2() {
+        let lines = vec![
+            String::from("shiny gold bags contain 2 dark red bags."),
+            String::from("dark red bags contain 2 dark orange bags."),
+            String::from("dark orange bags contain 2 dark yellow bags."),
+            String::from("dark yellow bags contain 2 dark green bags."),
+            String::from("dark green bags contain 2 dark blue bags."),
+            String::from("dark blue bags contain 2 dark violet bags."),
+            String::from("dark violet bags contain no other bags."),
+        ];
+
+        let count = BagRules::from_lines(&lines).unwrap().part2();
+        assert_eq!(count, 126);
+    }
+
+    #[test]
+    fn no_shiny_gold_rule() {
+        let rules = BagRules::parse("faded blue bags contain no other bags.\n").unwrap();
+        assert_eq!(rules.part1(), 0);
+        assert_eq!(rules.part2(), 0);
+    }
+
+    #[test]
+    fn malformed() {
+        let lines = vec![
+            String::from("shiny gold bags contain 2 dark red bags."),
+            String::from("dark red bags contain some dark orange bags."),
+        ];
+
+        let err = BagRules::from_lines(&lines).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 23));
+        assert_eq!(err.offending(), "some dark orange bags.");
+    }
+}
@@ -0,0 +1,253 @@
+use common::{Example, ParseError, ParseErrorKind, Solution};
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+/// How part 1's run of the program ended, with the accumulator's final
+/// value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    Looped(i32),
+    Terminated(i32),
+}
+
+/// A loop shows just the accumulator, which is the puzzle's answer.
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Looped(acc) => return write!(f, "{}", acc),
+            Outcome::Terminated(acc) => return write!(f, "terminated without looping (acc {})", acc),
+        }
+    }
+}
+
+/// The accumulator once the program has been made to terminate, or that no
+/// single jmp/nop swap does it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Repair {
+    Fixed(i32),
+    NoFix,
+}
+
+impl fmt::Display for Repair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Repair::Fixed(acc) => return write!(f, "{}", acc),
+            Repair::NoFix => return write!(f, "no single change stops the loop"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Program {
+    prg: Vec<Operation>,
+    pc: usize,
+    acc: i32,
+    visited: HashSet<usize>,
+}
+
+impl Program {
+    pub fn from_strings(lines: &[String]) -> Result<Program, ParseError> {
+        let program = lines
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| {
+                let (op, arg) = match line.split_once(' ') {
+                    Some(strs) => strs,
+                    None => {
+                        let expected = String::from("`<operation> <argument>`");
+                        return Err(ParseError::line(idx + 1, line, ParseErrorKind::Syntax(expected)));
+                    }
+                };
+                let val = arg
+                    .parse::<i32>()
+                    .map_err(|_| ParseError::at(idx + 1, line, arg, ParseErrorKind::InvalidNumber))?;
+                match op {
+                    "acc" => return Ok(Operation::Acc(val)),
+                    "jmp" => return Ok(Operation::Jmp(val)),
+                    "nop" => return Ok(Operation::Nop(val)),
+                    _ => {
+                        let kind = ParseErrorKind::Unrecognized(String::from("operation"));
+                        return Err(ParseError::at(idx + 1, line, op, kind));
+                    }
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Program {
+            prg: program,
+            pc: 0,
+            acc: 0,
+            visited: HashSet::new(),
+        });
+    }
+
+    pub fn step(&mut self) {
+        let op = &self.prg[self.pc];
+        match op {
+            Operation::Acc(val) => {
+                self.acc += val;
+                self.pc += 1;
+            }
+            Operation::Jmp(val) => {
+                let pc = self.pc as i32 + val;
+                self.pc = pc as usize;
+            }
+            Operation::Nop(_) => {
+                self.pc += 1;
+            }
+        }
+    }
+
+    pub fn execute(&mut self) -> Result<i32, i32> {
+        loop {
+            if self.pc >= self.prg.len() {
+                return Ok(self.acc);
+            }
+
+            if self.visited.contains(&self.pc) {
+                return Err(self.acc);
+            }
+
+            self.visited.insert(self.pc);
+            self.step();
+        }
+    }
+
+    pub fn fix_loop(&mut self) -> Repair {
+        let mut states: Vec<Program> = vec![];
+
+        // First find the loop. A program that already terminates needs no
+        // fixing.
+        loop {
+            if self.pc >= self.prg.len() {
+                return Repair::Fixed(self.acc);
+            }
+            if self.visited.contains(&self.pc) {
+                break;
+            }
+
+            states.push(self.clone());
+            self.visited.insert(self.pc);
+            self.step();
+        }
+
+        // Work back up the stack, trying substituing jmps for nops and
+        // vice-versa, and see if that fixes the loop.
+        while let Some(mut candidate) = states.pop() {
+
+            match candidate.prg[candidate.pc] {
+                Operation::Jmp(val) =>
+                    candidate.prg[candidate.pc] = Operation::Nop(val),
+                Operation::Nop(val) =>
+                    candidate.prg[candidate.pc] = Operation::Jmp(val),
+                _ => continue,
+            }
+
+            let result = candidate.execute();
+            match result {
+                Ok(val) => return Repair::Fixed(val),
+                Err(_) => continue,
+            }
+        }
+        return Repair::NoFix;
+    }
+}
+
+impl Solution for Program {
+    type Part1 = Outcome;
+    type Part2 = Repair;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n",
+        options: &[],
+    }];
+
+    fn parse(input: &str) -> Result<Program, ParseError> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        return Program::from_strings(&lines);
+    }
+
+    fn part1(&self) -> Outcome {
+        match self.clone().execute() {
+            Ok(result) => return Outcome::Terminated(result),
+            Err(result) => return Outcome::Looped(result),
+        }
+    }
+
+    fn part2(&self) -> Repair {
+        return self.clone().fix_loop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pt1_example() {
+        let lines = vec![
+            String::from("nop +0"),
+            String::from("acc +1"),
+            String::from("jmp +4"),
+            String::from("acc +3"),
+            String::from("jmp -3"),
+            String::from("acc -99"),
+            String::from("acc +1"),
+            String::from("jmp -4"),
+            String::from("acc +6"),
+        ];
+        let mut prg = Program::from_strings(&lines).unwrap();
+        let result = prg.execute();
+        assert_eq!(result, Err(5));
+    }
+
+    #[test]
+    fn pt2_example() {
+        let lines = vec![
+            String::from("nop +0"),
+            String::from("acc +1"),
+            String::from("jmp +4"),
+            String::from("acc +3"),
+            String::from("jmp -3"),
+            String::from("acc -99"),
+            String::from("acc +1"),
+            String::from("jmp -4"),
+            String::from("acc +6"),
+        ];
+        let mut prg = Program::from_strings(&lines).unwrap();
+        let result = prg.fix_loop();
+        assert_eq!(result, Repair::Fixed(8));
+    }
+
+    #[test]
+    fn terminating_program() {
+        let prg = Program::parse("nop +0\nacc +1\n").unwrap();
+        assert_eq!(prg.part1(), Outcome::Terminated(1));
+        assert_eq!(prg.part1().to_string(), "terminated without looping (acc 1)");
+        assert_eq!(prg.part2(), Repair::Fixed(1));
+    }
+
+    #[test]
+    fn unfixable_program() {
+        let prg = Program::parse("jmp +0\njmp -1\n").unwrap();
+        assert_eq!(prg.part2(), Repair::NoFix);
+        assert_eq!(prg.part2().to_string(), "no single change stops the loop");
+    }
+
+    #[test]
+    fn unrecognized_operation() {
+        let lines = vec![String::from("nop +0"), String::from("mul +2")];
+        let err = Program::from_strings(&lines).err().unwrap();
+        assert_eq!(err.kind, ParseErrorKind::Unrecognized(String::from("operation")));
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.offending(), "mul");
+    }
+}
@@ -0,0 +1,175 @@
+use common::{parse_numbers, Example, OptionError, Options, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::iter::FromIterator;
+
+/// The first number that isn't the sum of two in the window before it, if any.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Invalid {
+    Found(i64),
+    AllValid,
+}
+
+impl fmt::Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Invalid::Found(number) => return write!(f, "{}", number),
+            Invalid::AllValid => return write!(f, "every number is valid"),
+        }
+    }
+}
+
+/// The encryption weakness, or why there isn't one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Weakness {
+    Found(i64),
+    AllValid,
+    NoRange(i64),
+}
+
+impl fmt::Display for Weakness {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Weakness::Found(weakness) => return write!(f, "{}", weakness),
+            Weakness::AllValid => return write!(f, "every number is valid"),
+            Weakness::NoRange(target) => return write!(f, "no run of numbers sums to {}", target),
+        }
+    }
+}
+
+fn find_invalid(input: &[i64], window_size: usize) -> Option<i64> {
+    for idx in window_size..input.len() {
+        let candidate = input[idx];
+        let window_start = idx - window_size;
+        let window_end = idx;
+        let window = &input[window_start..window_end];
+        let window_set: HashSet<&i64> = HashSet::from_iter(window.iter());
+
+        let mut found = false;
+        for w in window {
+            if window_set.contains(&(candidate - w)) {
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            return Some(candidate);
+        }
+    }
+
+    return None;
+}
+
+fn find_encryption_weakness(input: &[i64], target: i64) -> Option<i64> {
+    let mut start = 0;
+    let mut end = 1;
+
+    while end <= input.len() {
+        let sum: i64 = input[start..end].iter().sum();
+
+        // The run has to be at least two numbers long.
+        if sum == target && end - start >= 2 {
+            let min: &i64 = input[start..end].iter().min().unwrap();
+            let max: &i64 = input[start..end].iter().max().unwrap();
+            return Some(*min + *max);
+        }
+
+        if sum <= target || end - start < 2 {
+            end += 1;
+        } else {
+            start += 1;
+        }
+    }
+
+    return None;
+}
+
+pub struct XmasData {
+    numbers: Vec<i64>,
+    window_size: usize,
+}
+
+impl Solution for XmasData {
+    type Part1 = Invalid;
+    type Part2 = Weakness;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        name: "example",
+        input: "\
+35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576\n",
+        options: &[("window", "5")],
+    }];
+
+    fn parse(input: &str) -> Result<XmasData, ParseError> {
+        return Ok(XmasData {
+            numbers: parse_numbers(input)?,
+            window_size: 25,
+        });
+    }
+
+    /// `window`: the length of the preamble, and of the window of previous
+    /// numbers that each number is checked against.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        if let Some(window_size) = options.get("window")? {
+            self.window_size = window_size;
+        }
+        return Ok(());
+    }
+
+    fn part1(&self) -> Invalid {
+        match find_invalid(&self.numbers, self.window_size) {
+            Some(invalid) => return Invalid::Found(invalid),
+            None => return Invalid::AllValid,
+        }
+    }
+
+    fn part2(&self) -> Weakness {
+        let invalid = match find_invalid(&self.numbers, self.window_size) {
+            Some(invalid) => invalid,
+            None => return Weakness::AllValid,
+        };
+        match find_encryption_weakness(&self.numbers, invalid) {
+            Some(weakness) => return Weakness::Found(weakness),
+            None => return Weakness::NoRange(invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pt1_example() {
+        let input: Vec<i64> = vec![
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+        let result = find_invalid(&input, 5);
+        assert_eq!(result, Some(127));
+    }
+
+    #[test]
+    fn pt2_example() {
+        let input: Vec<i64> = vec![
+            35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
+            576,
+        ];
+        let invalid = find_invalid(&input, 5).unwrap();
+        let result = find_encryption_weakness(&input, invalid);
+        assert_eq!(result, Some(62));
+    }
+
+    #[test]
+    fn no_weakness() {
+        let mut data = XmasData::parse("").unwrap();
+        assert_eq!(data.part1(), Invalid::AllValid);
+        assert_eq!(data.part2().to_string(), "every number is valid");
+
+        data = XmasData::parse("1\n2\n3\n100\n").unwrap();
+        data.window_size = 2;
+        assert_eq!(data.part1(), Invalid::Found(100));
+        assert_eq!(data.part2(), Weakness::NoRange(100));
+    }
+}
@@ -0,0 +1,240 @@
+mod direction;
+
+pub use direction::Direction;
+
+use common::{ParseError, ParseErrorKind};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, stored row by row. Cells are addressed by
+/// (x, y), with (0, 0) at the top left.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        return Grid {
+            cells: vec![fill; width * height],
+            width: width,
+            height: height,
+        };
+    }
+
+    /// Parses one row per line, mapping each character to a cell with
+    /// `cell`. Characters that `cell` rejects, ragged rows and empty input
+    /// are errors.
+    pub fn parse<F: Fn(char) -> Option<T>>(input: &str, cell: F) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (idx, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (offset, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let offending = &line[offset..offset + c.len_utf8()];
+                        let kind = ParseErrorKind::Unrecognized(String::from("grid cell"));
+                        return Err(ParseError::at(idx + 1, line, offending, kind));
+                    }
+                }
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let expected = format!("a row of width {}", width);
+                    return Err(ParseError::line(idx + 1, line, ParseErrorKind::Syntax(expected)));
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        match width {
+            None | Some(0) => return Err(ParseError::input(ParseErrorKind::Empty)),
+            Some(width) => {
+                return Ok(Grid {
+                    cells: cells,
+                    width: width,
+                    height: height,
+                });
+            }
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return Some(&self.cells[y * self.width + x]);
+    }
+
+    /// Treats the grid as a torus, so that any coordinates, including
+    /// negative ones, wrap around onto it.
+    pub fn get_wrapped(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        return &self[(x, y)];
+    }
+
+    /// The coordinates one step from (x, y) in `dir`, if still on the grid.
+    pub fn step(&self, x: usize, y: usize, dir: Direction) -> Option<(usize, usize)> {
+        let (x_inc, y_inc) = dir.to_vec();
+        let new_x = x.checked_add_signed(x_inc)?;
+        let new_y = y.checked_add_signed(y_inc)?;
+        if new_x >= self.width || new_y >= self.height {
+            return None;
+        }
+        return Some((new_x, new_y));
+    }
+
+    /// The up to four orthogonal neighbours of (x, y).
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return Direction::CARDINAL
+            .iter()
+            .filter_map(move |&dir| self.step(x, y, dir));
+    }
+
+    /// The up to eight neighbours of (x, y), including diagonals.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return Direction::ALL
+            .iter()
+            .filter_map(move |&dir| self.step(x, y, dir));
+    }
+
+    /// The coordinates visited walking from (x, y) in `dir` until leaving
+    /// the grid, not including (x, y) itself.
+    pub fn ray(&self, x: usize, y: usize, dir: Direction) -> Ray<'_, T> {
+        return Ray {
+            grid: self,
+            pos: (x, y),
+            dir: dir,
+        };
+    }
+
+    /// Every cell along with its coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        return self
+            .cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx % width, idx / width), cell));
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return self.cells.chunks(self.width);
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the grid", x, y);
+        return &self.cells[y * self.width + x];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the grid", x, y);
+        return &mut self.cells[y * self.width + x];
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        return Ok(());
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: (usize, usize),
+    dir: Direction,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let next = self.grid.step(self.pos.0, self.pos.1, self.dir)?;
+        self.pos = next;
+        return Some(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        return Grid::parse(input, |c| c.to_digit(10)).unwrap();
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("123\n4x6\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = Grid::parse("123\n45\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Syntax(String::from("a row of width 3")));
+
+        let err = Grid::parse("", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Empty);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789\n");
+        let corner: Vec<u32> = grid.neighbours8(0, 0).map(|p| grid[p]).collect();
+        assert_eq!(corner, vec![2, 5, 4]);
+        let centre: Vec<u32> = grid.neighbours4(1, 1).map(|p| grid[p]).collect();
+        assert_eq!(centre, vec![2, 6, 8, 4]);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn rays_and_wrapping() {
+        let grid = digits("123\n456\n789\n");
+        let ray: Vec<u32> = grid.ray(0, 2, Direction::NorthEast).map(|p| grid[p]).collect();
+        assert_eq!(ray, vec![5, 3]);
+        assert_eq!(grid.ray(2, 0, Direction::East).count(), 0);
+
+        assert_eq!(*grid.get_wrapped(4, 0), 2);
+        assert_eq!(*grid.get_wrapped(-1, -1), 9);
+    }
+}